@@ -0,0 +1,284 @@
+use std::fmt;
+
+use anchor_client::anchor_lang::{AnchorDeserialize, Discriminator};
+use base64::Engine;
+use on_chain_vault::events::*;
+
+const PROGRAM_DATA: &str = "Program data: ";
+
+/// A decoded vault program event.
+pub enum VaultEvent {
+    InitializeVault(InitializeVaultEvent),
+    MigrateVault(MigrateVaultEvent),
+    Deposit(DepositEvent),
+    Withdraw(WithdrawEvent),
+    ToggleLock(ToggleLockEvent),
+    ApproveDelegate(ApproveDelegateEvent),
+    RevokeDelegate(RevokeDelegateEvent),
+    DelegatedWithdraw(DelegatedWithdrawEvent),
+    DelegateStake(DelegateStakeEvent),
+    DeactivateStake(DeactivateStakeEvent),
+    WithdrawStake(WithdrawStakeEvent),
+    SetBeneficiary(SetBeneficiaryEvent),
+    InheritanceBalanceClaimed(InheritanceBalanceClaimedEvent),
+    InheritanceAuthorityClaimed(InheritanceAuthorityClaimedEvent),
+    EscrowFunded(EscrowFundedEvent),
+    EscrowReleased(EscrowReleasedEvent),
+    EscrowRefunded(EscrowRefundedEvent),
+    StreamCreated(StreamCreatedEvent),
+    StreamWithdraw(StreamWithdrawEvent),
+    StreamCancelled(StreamCancelledEvent),
+    CreatePullAuthorization(CreatePullAuthorizationEvent),
+    RevokePullAuthorization(RevokePullAuthorizationEvent),
+    Pull(PullEvent),
+    DepositPause(DepositPauseEvent),
+    WithdrawPause(WithdrawPauseEvent),
+    SetGuardians(SetGuardiansEvent),
+    RecoveryProposed(RecoveryProposedEvent),
+    RecoveryApproved(RecoveryApprovedEvent),
+    RecoveryVetoed(RecoveryVetoedEvent),
+    RecoveryCancelled(RecoveryCancelledEvent),
+    RecoveryExecuted(RecoveryExecutedEvent),
+}
+
+/// Decodes every vault event found in a transaction's log messages.
+/// Log lines that are not vault events are skipped.
+pub fn parse_logs(logs: &[String]) -> Vec<VaultEvent> {
+    logs.iter()
+        .filter_map(|log| log.strip_prefix(PROGRAM_DATA))
+        .filter_map(|data| base64::engine::general_purpose::STANDARD.decode(data).ok())
+        .filter_map(|bytes| decode(&bytes))
+        .collect()
+}
+
+fn decode(bytes: &[u8]) -> Option<VaultEvent> {
+    fn event<T: AnchorDeserialize + Discriminator>(bytes: &[u8]) -> Option<T> {
+        let data = bytes.strip_prefix(T::DISCRIMINATOR)?;
+        T::deserialize(&mut &data[..]).ok()
+    }
+
+    event(bytes)
+        .map(VaultEvent::InitializeVault)
+        .or_else(|| event(bytes).map(VaultEvent::MigrateVault))
+        .or_else(|| event(bytes).map(VaultEvent::Deposit))
+        .or_else(|| event(bytes).map(VaultEvent::Withdraw))
+        .or_else(|| event(bytes).map(VaultEvent::ToggleLock))
+        .or_else(|| event(bytes).map(VaultEvent::ApproveDelegate))
+        .or_else(|| event(bytes).map(VaultEvent::RevokeDelegate))
+        .or_else(|| event(bytes).map(VaultEvent::DelegatedWithdraw))
+        .or_else(|| event(bytes).map(VaultEvent::DelegateStake))
+        .or_else(|| event(bytes).map(VaultEvent::DeactivateStake))
+        .or_else(|| event(bytes).map(VaultEvent::WithdrawStake))
+        .or_else(|| event(bytes).map(VaultEvent::SetBeneficiary))
+        .or_else(|| event(bytes).map(VaultEvent::InheritanceBalanceClaimed))
+        .or_else(|| event(bytes).map(VaultEvent::InheritanceAuthorityClaimed))
+        .or_else(|| event(bytes).map(VaultEvent::EscrowFunded))
+        .or_else(|| event(bytes).map(VaultEvent::EscrowReleased))
+        .or_else(|| event(bytes).map(VaultEvent::EscrowRefunded))
+        .or_else(|| event(bytes).map(VaultEvent::StreamCreated))
+        .or_else(|| event(bytes).map(VaultEvent::StreamWithdraw))
+        .or_else(|| event(bytes).map(VaultEvent::StreamCancelled))
+        .or_else(|| event(bytes).map(VaultEvent::CreatePullAuthorization))
+        .or_else(|| event(bytes).map(VaultEvent::RevokePullAuthorization))
+        .or_else(|| event(bytes).map(VaultEvent::Pull))
+        .or_else(|| event(bytes).map(VaultEvent::DepositPause))
+        .or_else(|| event(bytes).map(VaultEvent::WithdrawPause))
+        .or_else(|| event(bytes).map(VaultEvent::SetGuardians))
+        .or_else(|| event(bytes).map(VaultEvent::RecoveryProposed))
+        .or_else(|| event(bytes).map(VaultEvent::RecoveryApproved))
+        .or_else(|| event(bytes).map(VaultEvent::RecoveryVetoed))
+        .or_else(|| event(bytes).map(VaultEvent::RecoveryCancelled))
+        .or_else(|| event(bytes).map(VaultEvent::RecoveryExecuted))
+}
+
+impl fmt::Display for VaultEvent {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VaultEvent::InitializeVault(e) => write!(
+                f,
+                "init vault={} authority={} locked={}",
+                e.vault, e.vault_authority, e.locked
+            ),
+            VaultEvent::MigrateVault(e) => write!(
+                f,
+                "migrate vault={} authority={} locked={}",
+                e.vault, e.vault_authority, e.locked
+            ),
+            VaultEvent::Deposit(e) => {
+                write!(f, "deposit vault={} user={} amount={}", e.vault, e.user, e.amount)
+            }
+            VaultEvent::Withdraw(e) => write!(
+                f,
+                "withdraw vault={} authority={} amount={}",
+                e.vault, e.vault_authority, e.amount
+            ),
+            VaultEvent::ToggleLock(e) => write!(
+                f,
+                "toggle-lock vault={} authority={} locked={}",
+                e.vault, e.vault_authority, e.locked
+            ),
+            VaultEvent::ApproveDelegate(e) => write!(
+                f,
+                "approve-delegate vault={} delegate={} allowance={} expiry={}",
+                e.vault, e.delegate, e.allowance, e.expiry
+            ),
+            VaultEvent::RevokeDelegate(e) => {
+                write!(f, "revoke-delegate vault={} delegate={}", e.vault, e.delegate)
+            }
+            VaultEvent::DelegatedWithdraw(e) => write!(
+                f,
+                "delegated-withdraw vault={} delegate={} amount={} remaining={}",
+                e.vault, e.delegate, e.amount, e.remaining_allowance
+            ),
+            VaultEvent::DelegateStake(e) => write!(
+                f,
+                "delegate-stake vault={} stake={} vote={} amount={}",
+                e.vault, e.stake_account, e.vote_account, e.amount
+            ),
+            VaultEvent::DeactivateStake(e) => {
+                write!(f, "deactivate-stake vault={} stake={}", e.vault, e.stake_account)
+            }
+            VaultEvent::WithdrawStake(e) => write!(
+                f,
+                "withdraw-stake vault={} stake={} amount={}",
+                e.vault, e.stake_account, e.amount
+            ),
+            VaultEvent::SetBeneficiary(e) => write!(
+                f,
+                "set-beneficiary vault={} beneficiary={} inactivity-period={}",
+                e.vault,
+                e.beneficiary.map_or("none".to_string(), |b| b.to_string()),
+                e.inactivity_period
+            ),
+            VaultEvent::InheritanceBalanceClaimed(e) => write!(
+                f,
+                "inheritance-balance vault={} beneficiary={} amount={}",
+                e.vault, e.beneficiary, e.amount
+            ),
+            VaultEvent::InheritanceAuthorityClaimed(e) => write!(
+                f,
+                "inheritance-authority vault={} previous={} new={}",
+                e.vault, e.previous_authority, e.new_authority
+            ),
+            VaultEvent::EscrowFunded(e) => write!(
+                f,
+                "escrow-funded escrow={} depositor={} payee={} arbiter={} amount={} expiry={}",
+                e.escrow, e.depositor, e.payee, e.arbiter, e.amount, e.expiry
+            ),
+            VaultEvent::EscrowReleased(e) => write!(
+                f,
+                "escrow-released escrow={} payee={} by={} amount={}",
+                e.escrow, e.payee, e.released_by, e.amount
+            ),
+            VaultEvent::EscrowRefunded(e) => write!(
+                f,
+                "escrow-refunded escrow={} depositor={} by={} amount={}",
+                e.escrow, e.depositor, e.refunded_by, e.amount
+            ),
+            VaultEvent::StreamCreated(e) => write!(
+                f,
+                "stream-created vault={} stream={} recipient={} rate={} start={} end={}",
+                e.vault, e.stream, e.recipient, e.rate_per_second, e.start, e.end
+            ),
+            VaultEvent::StreamWithdraw(e) => write!(
+                f,
+                "stream-withdraw vault={} stream={} recipient={} amount={}",
+                e.vault, e.stream, e.recipient, e.amount
+            ),
+            VaultEvent::StreamCancelled(e) => write!(
+                f,
+                "stream-cancelled vault={} stream={} recipient={} settled={} returned={}",
+                e.vault, e.stream, e.recipient, e.settled, e.returned
+            ),
+            VaultEvent::CreatePullAuthorization(e) => write!(
+                f,
+                "create-pull vault={} merchant={} amount={} period={}",
+                e.vault, e.merchant, e.amount_per_period, e.period
+            ),
+            VaultEvent::RevokePullAuthorization(e) => {
+                write!(f, "revoke-pull vault={} merchant={}", e.vault, e.merchant)
+            }
+            VaultEvent::Pull(e) => write!(
+                f,
+                "pull vault={} merchant={} amount={} next={}",
+                e.vault, e.merchant, e.amount, e.next_pull_at
+            ),
+            VaultEvent::DepositPause(e) => write!(
+                f,
+                "deposit-pause vault={} authority={} paused={}",
+                e.vault, e.vault_authority, e.paused
+            ),
+            VaultEvent::WithdrawPause(e) => write!(
+                f,
+                "withdraw-pause vault={} authority={} paused={}",
+                e.vault, e.vault_authority, e.paused
+            ),
+            VaultEvent::SetGuardians(e) => write!(
+                f,
+                "set-guardians vault={} guardians={} threshold={} delay={}",
+                e.vault,
+                e.guardians.len(),
+                e.threshold,
+                e.recovery_delay
+            ),
+            VaultEvent::RecoveryProposed(e) => write!(
+                f,
+                "recovery-proposed vault={} proposer={} new={}",
+                e.vault, e.proposer, e.new_authority
+            ),
+            VaultEvent::RecoveryApproved(e) => write!(
+                f,
+                "recovery-approved vault={} guardian={} approvals={} executable_at={}",
+                e.vault, e.guardian, e.approvals, e.executable_at
+            ),
+            VaultEvent::RecoveryVetoed(e) => write!(
+                f,
+                "recovery-vetoed vault={} authority={} new={}",
+                e.vault, e.vault_authority, e.new_authority
+            ),
+            VaultEvent::RecoveryCancelled(e) => write!(
+                f,
+                "recovery-cancelled vault={} proposer={} new={}",
+                e.vault, e.proposer, e.new_authority
+            ),
+            VaultEvent::RecoveryExecuted(e) => write!(
+                f,
+                "recovery-executed vault={} previous={} new={}",
+                e.vault, e.previous_authority, e.new_authority
+            ),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::anchor_lang::{prelude::Pubkey, Event};
+
+    #[test]
+    fn decodes_program_data_logs() {
+        let deposit = DepositEvent {
+            amount: 42,
+            user: Pubkey::new_unique(),
+            vault: Pubkey::new_unique(),
+        };
+        let logs = vec![
+            "Program log: Instruction: Deposit".to_string(),
+            format!(
+                "{PROGRAM_DATA}{}",
+                base64::engine::general_purpose::STANDARD.encode(deposit.data())
+            ),
+        ];
+
+        let events = parse_logs(&logs);
+        assert_eq!(events.len(), 1);
+        match &events[0] {
+            VaultEvent::Deposit(e) => {
+                assert_eq!(e.amount, 42);
+                assert_eq!(e.user, deposit.user);
+                assert_eq!(e.vault, deposit.vault);
+            }
+            _ => panic!("expected a deposit event"),
+        }
+    }
+}
@@ -0,0 +1,326 @@
+//! Command-line tool for managing on-chain vaults.
+//!
+//! Every transaction is signed with the keypair given by `--keypair`
+//! (a JSON byte array such as `payer.json`). With `--sign-only` the
+//! signed transaction is printed as base64 instead of being sent, so it
+//! can be broadcast later from another machine.
+
+mod events;
+
+use anchor_client::anchor_lang::{
+    AccountDeserialize, InstructionData, Space, ToAccountMetas,
+};
+use anchor_client::solana_client::rpc_client::RpcClient;
+use anchor_client::solana_client::rpc_config::RpcTransactionConfig;
+use anchor_client::solana_sdk::commitment_config::CommitmentConfig;
+use anchor_client::solana_sdk::hash::Hash;
+use anchor_client::solana_sdk::instruction::Instruction;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use anchor_client::solana_sdk::signature::{read_keypair_file, Keypair, Signer};
+use anchor_client::anchor_lang::system_program;
+use anchor_client::solana_sdk::transaction::Transaction;
+use anyhow::{anyhow, Context, Result};
+use base64::Engine;
+use clap::{Parser, Subcommand, ValueEnum};
+use on_chain_vault::state::Vault;
+
+#[derive(Parser)]
+#[command(name = "vault", about = "Manage on-chain vaults")]
+struct Cli {
+    /// RPC endpoint of the cluster
+    #[arg(long, short = 'u', global = true, default_value = "http://127.0.0.1:8899")]
+    url: String,
+
+    /// Keypair file used to sign and pay for transactions
+    #[arg(long, short = 'k', global = true, default_value = "payer.json")]
+    keypair: String,
+
+    /// Print the signed transaction instead of sending it
+    #[arg(long, global = true, requires = "blockhash")]
+    sign_only: bool,
+
+    /// Recent blockhash to sign with, required by --sign-only
+    #[arg(long, global = true)]
+    blockhash: Option<Hash>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Create the vault owned by the keypair
+    Init {
+        /// Create the vault in the locked state
+        #[arg(long)]
+        locked: bool,
+    },
+    /// Migrate the keypair's vault created by an earlier program version
+    Migrate,
+    /// Deposit lamports into a vault
+    Deposit {
+        /// Amount in lamports
+        amount: u64,
+        /// Target vault, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Withdraw lamports from a vault the keypair is the authority of
+    Withdraw {
+        /// Amount in lamports
+        amount: u64,
+        /// Vault to withdraw from, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Lock a vault the keypair is the authority of
+    Lock {
+        /// Vault to lock, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Unlock a vault the keypair is the authority of
+    Unlock {
+        /// Vault to unlock, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Pause deposits or withdrawals on a vault the keypair is the authority of
+    Pause {
+        #[arg(value_enum)]
+        flow: Flow,
+        /// Vault to pause, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Resume deposits or withdrawals on a vault the keypair is the authority of
+    Resume {
+        #[arg(value_enum)]
+        flow: Flow,
+        /// Vault to resume, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// Show the decoded vault and its withdrawable balance
+    Show {
+        /// Vault to show, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+    },
+    /// List the events emitted for a vault, newest first
+    History {
+        /// Vault to inspect, defaults to the keypair's own vault
+        #[arg(long)]
+        vault: Option<Pubkey>,
+        /// Maximum number of transactions to inspect
+        #[arg(long, default_value_t = 25)]
+        limit: usize,
+    },
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum Flow {
+    Deposits,
+    Withdrawals,
+}
+
+fn main() -> Result<()> {
+    let cli = Cli::parse();
+    let payer = read_keypair_file(&cli.keypair)
+        .map_err(|e| anyhow!("failed to read keypair {}: {e}", cli.keypair))?;
+    let rpc = RpcClient::new_with_commitment(cli.url.clone(), CommitmentConfig::confirmed());
+    let authority = payer.pubkey();
+
+    match cli.command {
+        Command::Init { locked } => {
+            let vault = vault_address(&authority);
+            let ix = instruction(
+                on_chain_vault::accounts::InitializeVault {
+                    vault_authority: authority,
+                    vault,
+                    system_program: system_program::ID,
+                },
+                on_chain_vault::instruction::InitVault { locked },
+            );
+            submit(&cli, &rpc, &payer, &[ix])
+        }
+        Command::Migrate => {
+            let ix = instruction(
+                on_chain_vault::accounts::MigrateVault {
+                    vault_authority: authority,
+                    vault: vault_address(&authority),
+                    system_program: system_program::ID,
+                },
+                on_chain_vault::instruction::MigrateVault {},
+            );
+            submit(&cli, &rpc, &payer, &[ix])
+        }
+        Command::Deposit { amount, vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            let ix = instruction(
+                on_chain_vault::accounts::Deposit {
+                    user: authority,
+                    vault,
+                    system_program: system_program::ID,
+                },
+                on_chain_vault::instruction::Deposit { amount },
+            );
+            submit(&cli, &rpc, &payer, &[ix])
+        }
+        Command::Withdraw { amount, vault } => {
+            let ix = instruction(
+                on_chain_vault::accounts::Withdraw {
+                    vault_authority: authority,
+                    vault: vault.unwrap_or_else(|| vault_address(&authority)),
+                    system_program: system_program::ID,
+                },
+                on_chain_vault::instruction::Withdraw { amount },
+            );
+            submit(&cli, &rpc, &payer, &[ix])
+        }
+        Command::Lock { vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            set_locked(&cli, &rpc, &payer, &vault, true)
+        }
+        Command::Unlock { vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            set_locked(&cli, &rpc, &payer, &vault, false)
+        }
+        Command::Pause { flow, vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            set_paused(&cli, &rpc, &payer, &vault, flow, true)
+        }
+        Command::Resume { flow, vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            set_paused(&cli, &rpc, &payer, &vault, flow, false)
+        }
+        Command::Show { vault } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            show(&rpc, &vault)
+        }
+        Command::History { vault, limit } => {
+            let vault = vault.unwrap_or_else(|| vault_address(&authority));
+            history(&rpc, &vault, limit)
+        }
+    }
+}
+
+fn vault_address(authority: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"vault", authority.as_ref()], &on_chain_vault::ID).0
+}
+
+fn instruction(accounts: impl ToAccountMetas, args: impl InstructionData) -> Instruction {
+    Instruction {
+        program_id: on_chain_vault::ID,
+        accounts: accounts.to_account_metas(None),
+        data: args.data(),
+    }
+}
+
+fn submit(cli: &Cli, rpc: &RpcClient, payer: &Keypair, ixs: &[Instruction]) -> Result<()> {
+    let blockhash = match cli.blockhash {
+        Some(blockhash) => blockhash,
+        None => rpc.get_latest_blockhash()?,
+    };
+    let tx = Transaction::new_signed_with_payer(ixs, Some(&payer.pubkey()), &[payer], blockhash);
+
+    if cli.sign_only {
+        let bytes = bincode::serialize(&tx)?;
+        println!("{}", base64::engine::general_purpose::STANDARD.encode(bytes));
+        return Ok(());
+    }
+
+    let signature = rpc.send_and_confirm_transaction(&tx)?;
+    println!("{signature}");
+    Ok(())
+}
+
+/// Locking pauses both flows in one transaction. Unlike `toggle_lock`,
+/// setting the pause flags is idempotent, so the vault is never read and
+/// `--sign-only` signs exactly what was asked for.
+fn set_locked(cli: &Cli, rpc: &RpcClient, payer: &Keypair, vault: &Pubkey, locked: bool) -> Result<()> {
+    let ixs = [
+        pause_instruction(payer, vault, Flow::Deposits, locked),
+        pause_instruction(payer, vault, Flow::Withdrawals, locked),
+    ];
+    submit(cli, rpc, payer, &ixs)
+}
+
+fn set_paused(cli: &Cli, rpc: &RpcClient, payer: &Keypair, vault: &Pubkey, flow: Flow, paused: bool) -> Result<()> {
+    submit(cli, rpc, payer, &[pause_instruction(payer, vault, flow, paused)])
+}
+
+fn pause_instruction(payer: &Keypair, vault: &Pubkey, flow: Flow, paused: bool) -> Instruction {
+    let vault_authority = payer.pubkey();
+    let vault = *vault;
+
+    match flow {
+        Flow::Deposits => instruction(
+            on_chain_vault::accounts::SetDepositPause {
+                vault_authority,
+                vault,
+            },
+            on_chain_vault::instruction::SetDepositPause { paused },
+        ),
+        Flow::Withdrawals => instruction(
+            on_chain_vault::accounts::SetWithdrawPause {
+                vault_authority,
+                vault,
+            },
+            on_chain_vault::instruction::SetWithdrawPause { paused },
+        ),
+    }
+}
+
+fn fetch_vault(rpc: &RpcClient, vault: &Pubkey) -> Result<Vault> {
+    let data = rpc
+        .get_account_data(vault)
+        .with_context(|| format!("failed to fetch vault {vault}"))?;
+    Ok(Vault::try_deserialize(&mut data.as_slice())?)
+}
+
+fn show(rpc: &RpcClient, vault: &Pubkey) -> Result<()> {
+    let state = fetch_vault(rpc, vault)?;
+    let lamports = rpc.get_balance(vault)?;
+    let rent = rpc.get_minimum_balance_for_rent_exemption(8 + Vault::INIT_SPACE)?;
+
+    println!("vault:              {vault}");
+    println!("authority:          {}", state.vault_authority);
+    println!("locked:             {}", state.locked);
+    println!("deposits paused:    {}", state.deposits_paused);
+    println!("withdrawals paused: {}", state.withdrawals_paused);
+    if let Some(beneficiary) = state.beneficiary {
+        println!("beneficiary:        {beneficiary}");
+        println!("inactivity:         {}s", state.inactivity_period);
+    }
+    println!("last heartbeat:     {}", state.last_heartbeat);
+    println!("balance:            {lamports}");
+    if state.locked || state.withdrawals_paused {
+        println!("withdrawable:       0 (withdrawals paused)");
+    } else {
+        println!("withdrawable:       {}", lamports.saturating_sub(rent));
+    }
+    Ok(())
+}
+
+fn history(rpc: &RpcClient, vault: &Pubkey, limit: usize) -> Result<()> {
+    let config = RpcTransactionConfig {
+        commitment: Some(CommitmentConfig::confirmed()),
+        max_supported_transaction_version: Some(0),
+        ..RpcTransactionConfig::default()
+    };
+
+    for status in rpc.get_signatures_for_address(vault)?.into_iter().take(limit) {
+        let signature = status.signature.parse()?;
+        let tx = rpc.get_transaction_with_config(&signature, config)?;
+        let logs: Option<Vec<String>> = tx
+            .transaction
+            .meta
+            .and_then(|meta| meta.log_messages.into());
+
+        for event in events::parse_logs(&logs.unwrap_or_default()) {
+            println!("{} slot {} {event}", status.signature, status.slot);
+        }
+    }
+    Ok(())
+}
@@ -8,4 +8,44 @@ pub enum VaultError {
     Overflow,
     #[msg("Insufficient balance")]
     InsufficientBalance,
+    #[msg("Delegate allowance has expired")]
+    DelegateExpired,
+    #[msg("Amount exceeds delegate allowance")]
+    AllowanceExceeded,
+    #[msg("Inactivity period must be positive")]
+    InvalidInactivityPeriod,
+    #[msg("Signer is not the vault beneficiary")]
+    NotBeneficiary,
+    #[msg("Vault authority is still active")]
+    AuthorityStillActive,
+    #[msg("Signer is not allowed to settle this escrow")]
+    UnauthorizedEscrowParty,
+    #[msg("Escrow has not expired yet")]
+    EscrowNotExpired,
+    #[msg("Stream must end after it starts and have a positive rate")]
+    InvalidStream,
+    #[msg("Nothing has accrued on this stream")]
+    NothingAccrued,
+    #[msg("Pull period must be positive")]
+    InvalidPullPeriod,
+    #[msg("Already pulled in this period")]
+    PullTooEarly,
+    #[msg("Deposits are paused")]
+    DepositsPaused,
+    #[msg("Withdrawals are paused")]
+    WithdrawalsPaused,
+    #[msg("Guardians must be unique, at most 10, with a threshold between 1 and their count, or none with a zero threshold")]
+    InvalidGuardianSet,
+    #[msg("Recovery delay must be positive")]
+    InvalidRecoveryDelay,
+    #[msg("Signer is not a vault guardian")]
+    NotGuardian,
+    #[msg("Guardian has already approved this recovery")]
+    AlreadyApproved,
+    #[msg("Recovery threshold not met or waiting period not over")]
+    RecoveryNotReady,
+    #[msg("Vault authority changed since the recovery was proposed")]
+    StaleRecovery,
+    #[msg("Vault already has the current layout")]
+    VaultUpToDate,
 }
\ No newline at end of file
@@ -7,6 +7,13 @@ pub struct InitializeVaultEvent {
     pub locked: bool,
 }
 
+#[event]
+pub struct MigrateVaultEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub locked: bool,
+}
+
 #[event]
 pub struct DepositEvent {
     pub amount: u64,
@@ -26,4 +33,204 @@ pub struct ToggleLockEvent {
     pub vault: Pubkey,
     pub vault_authority: Pubkey,
     pub locked: bool,
-}
\ No newline at end of file
+}
+
+#[event]
+pub struct ApproveDelegateEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub delegate: Pubkey,
+    pub allowance: u64,
+    pub expiry: i64,
+}
+
+#[event]
+pub struct RevokeDelegateEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub delegate: Pubkey,
+}
+
+#[event]
+pub struct DelegatedWithdrawEvent {
+    pub amount: u64,
+    pub delegate: Pubkey,
+    pub vault: Pubkey,
+    pub remaining_allowance: u64,
+}
+
+#[event]
+pub struct DelegateStakeEvent {
+    pub amount: u64,
+    pub vault: Pubkey,
+    pub stake_account: Pubkey,
+    pub vote_account: Pubkey,
+}
+
+#[event]
+pub struct DeactivateStakeEvent {
+    pub vault: Pubkey,
+    pub stake_account: Pubkey,
+}
+
+#[event]
+pub struct WithdrawStakeEvent {
+    pub amount: u64,
+    pub vault: Pubkey,
+    pub stake_account: Pubkey,
+}
+
+#[event]
+pub struct SetBeneficiaryEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub beneficiary: Option<Pubkey>,
+    pub inactivity_period: i64,
+}
+
+#[event]
+pub struct InheritanceBalanceClaimedEvent {
+    pub amount: u64,
+    pub beneficiary: Pubkey,
+    pub vault: Pubkey,
+}
+
+#[event]
+pub struct InheritanceAuthorityClaimedEvent {
+    pub vault: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct EscrowFundedEvent {
+    pub escrow: Pubkey,
+    pub depositor: Pubkey,
+    pub payee: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount: u64,
+    pub expiry: i64,
+}
+
+#[event]
+pub struct EscrowReleasedEvent {
+    pub escrow: Pubkey,
+    pub payee: Pubkey,
+    pub released_by: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowRefundedEvent {
+    pub escrow: Pubkey,
+    pub depositor: Pubkey,
+    pub refunded_by: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct StreamCreatedEvent {
+    pub stream: Pubkey,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub rate_per_second: u64,
+    pub start: i64,
+    pub end: i64,
+}
+
+#[event]
+pub struct StreamWithdrawEvent {
+    pub amount: u64,
+    pub stream: Pubkey,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+}
+
+#[event]
+pub struct StreamCancelledEvent {
+    pub stream: Pubkey,
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    pub settled: u64,
+    pub returned: u64,
+}
+
+#[event]
+pub struct CreatePullAuthorizationEvent {
+    pub vault: Pubkey,
+    pub merchant: Pubkey,
+    pub amount_per_period: u64,
+    pub period: i64,
+}
+
+#[event]
+pub struct RevokePullAuthorizationEvent {
+    pub vault: Pubkey,
+    pub merchant: Pubkey,
+}
+
+#[event]
+pub struct PullEvent {
+    pub amount: u64,
+    pub vault: Pubkey,
+    pub merchant: Pubkey,
+    pub next_pull_at: i64,
+}
+
+#[event]
+pub struct DepositPauseEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct WithdrawPauseEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub paused: bool,
+}
+
+#[event]
+pub struct SetGuardiansEvent {
+    pub vault: Pubkey,
+    pub guardians: Vec<Pubkey>,
+    pub threshold: u8,
+    pub recovery_delay: i64,
+}
+
+#[event]
+pub struct RecoveryProposedEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryApprovedEvent {
+    pub vault: Pubkey,
+    pub guardian: Pubkey,
+    pub approvals: u8,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct RecoveryVetoedEvent {
+    pub vault: Pubkey,
+    pub vault_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryCancelledEvent {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RecoveryExecutedEvent {
+    pub vault: Pubkey,
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
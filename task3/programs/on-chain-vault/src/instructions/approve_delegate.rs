@@ -0,0 +1,64 @@
+//-------------------------------------------------------------------------------
+//
+// Approve Delegate Instruction
+//
+// - Only the vault authority can approve a delegate
+// - Creates a delegate PDA holding the allowance and its expiry
+// - Emits an approve delegate event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Delegate, Vault};
+use crate::events::ApproveDelegateEvent;
+
+#[derive(Accounts)]
+#[instruction(delegate: Pubkey)]
+pub struct ApproveDelegate<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        // space = discriminant + account size
+        space = 8 + Delegate::INIT_SPACE,
+        seeds = [b"delegate", vault.key().as_ref(), delegate.as_ref()],
+        bump
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _approve_delegate(
+    ctx: Context<ApproveDelegate>,
+    delegate: Pubkey,
+    allowance: u64,
+    expiry: i64,
+) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    let delegate_account = &mut ctx.accounts.delegate_account;
+
+    delegate_account.vault = ctx.accounts.vault.key();
+    delegate_account.delegate = delegate;
+    delegate_account.allowance = allowance;
+    delegate_account.expiry = expiry;
+    delegate_account.bump = ctx.bumps.delegate_account;
+
+    emit!(ApproveDelegateEvent {
+        vault: delegate_account.vault,
+        vault_authority: ctx.accounts.vault_authority.key(),
+        delegate,
+        allowance,
+        expiry,
+    });
+
+    Ok(())
+}
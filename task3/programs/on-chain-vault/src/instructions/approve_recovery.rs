@@ -0,0 +1,70 @@
+//-------------------------------------------------------------------------------
+//
+// Approve Recovery Instruction
+//
+// - Only a guardian can approve, and only once per proposal
+// - Approvals from removed guardians are dropped, so the list never outgrows
+//   the guardian set
+// - When the threshold is first reached, the waiting period starts and the
+//   proposal becomes executable once it is over
+// - Emits a recovery approved event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{GuardianSet, RecoveryProposal};
+use crate::errors::VaultError;
+use crate::events::RecoveryApprovedEvent;
+
+#[derive(Accounts)]
+pub struct ApproveRecovery<'info> {
+    pub guardian: Signer<'info>,
+    #[account(
+        constraint = guardian_set.guardians.contains(&guardian.key()) @ VaultError::NotGuardian,
+        seeds = [b"guardians", recovery_proposal.vault.as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        mut,
+        seeds = [
+            b"recovery",
+            recovery_proposal.vault.as_ref(),
+            recovery_proposal.proposer.as_ref(),
+        ],
+        bump = recovery_proposal.bump,
+    )]
+    pub recovery_proposal: Account<'info, RecoveryProposal>,
+}
+
+pub fn _approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+    let guardian_set = &ctx.accounts.guardian_set;
+    let guardian = ctx.accounts.guardian.key();
+    let recovery_proposal = &mut ctx.accounts.recovery_proposal;
+
+    require!(
+        !recovery_proposal.approvals.contains(&guardian),
+        VaultError::AlreadyApproved
+    );
+    recovery_proposal
+        .approvals
+        .retain(|approval| guardian_set.guardians.contains(approval));
+    recovery_proposal.approvals.push(guardian);
+
+    // Start the waiting period the first time the threshold is reached
+    let approvals = recovery_proposal.valid_approvals(guardian_set);
+    if recovery_proposal.executable_at == 0 && approvals >= guardian_set.threshold as usize {
+        recovery_proposal.executable_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(guardian_set.recovery_delay)
+            .ok_or(VaultError::Overflow)?;
+    }
+
+    emit!(RecoveryApprovedEvent {
+        vault: recovery_proposal.vault,
+        guardian,
+        approvals: approvals as u8,
+        executable_at: recovery_proposal.executable_at,
+    });
+    Ok(())
+}
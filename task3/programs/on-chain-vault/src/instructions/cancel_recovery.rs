@@ -0,0 +1,43 @@
+//-------------------------------------------------------------------------------
+//
+// Cancel Recovery Instruction
+//
+// - Only the guardian who proposed a recovery can cancel it, even after being
+//   removed from the guardian set
+// - Closes the proposal and returns its rent to the proposer
+// - Emits a recovery cancelled event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::RecoveryProposal;
+use crate::events::RecoveryCancelledEvent;
+
+#[derive(Accounts)]
+pub struct CancelRecovery<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    #[account(
+        mut,
+        close = proposer, // Close the proposal and return rent to the proposer
+        has_one = proposer, // Ensure only the proposer can cancel
+        seeds = [
+            b"recovery",
+            recovery_proposal.vault.as_ref(),
+            proposer.key().as_ref(),
+        ],
+        bump = recovery_proposal.bump,
+    )]
+    pub recovery_proposal: Account<'info, RecoveryProposal>,
+}
+
+pub fn _cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+    let recovery_proposal = &ctx.accounts.recovery_proposal;
+
+    emit!(RecoveryCancelledEvent {
+        vault: recovery_proposal.vault,
+        proposer: recovery_proposal.proposer,
+        new_authority: recovery_proposal.new_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,85 @@
+//-------------------------------------------------------------------------------
+//
+// Cancel Stream Instruction
+//
+// - Only the vault authority can cancel, and only while the vault is unlocked
+// - Pays the accrued, unwithdrawn part to the recipient
+// - The unaccrued rest never left the vault, so it simply stays there
+// - Closes the stream and returns its rent to the vault authority
+// - Emits a stream cancelled event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::StreamCancelledEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct CancelStream<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: checked against the stream recipient
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = vault_authority, // Close the stream account and return rent to the authority
+        has_one = vault, // Ensure the stream draws from this vault
+        has_one = recipient,
+        seeds = [
+            b"stream",
+            vault.key().as_ref(),
+            recipient.key().as_ref(),
+            stream.stream_id.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+    )]
+    pub stream: Account<'info, Stream>,
+}
+
+pub fn _cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    let vault = &ctx.accounts.vault;
+    let stream = &ctx.accounts.stream;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    let now = Clock::get()?.unix_timestamp;
+    let settled = stream.withdrawable(now)?;
+    let returned = stream
+        .accrued(stream.end)?
+        .checked_sub(stream.accrued(now)?)
+        .ok_or(VaultError::Overflow)?;
+
+    // Verify that the vault has enough balance to settle
+    let vault_balance = vault.to_account_info().lamports();
+    require_gte!(vault_balance, settled, VaultError::InsufficientBalance);
+
+    // Transfer the accrued lamports from vault to recipient
+    transfer_from_program_account(
+        &vault.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        settled,
+    )?;
+
+    emit!(StreamCancelledEvent {
+        stream: stream.key(),
+        vault: vault.key(),
+        recipient: stream.recipient,
+        settled,
+        returned,
+    });
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//-------------------------------------------------------------------------------
+//
+// Claim Inheritance Instruction
+//
+// - Only the beneficiary can claim, once the authority has been inactive
+//   for longer than the inactivity period
+// - Balance: moves everything above the rent-exempt minimum to the beneficiary
+//   (blocked while the vault is locked, like a withdrawal)
+// - Authority: makes the beneficiary the new vault authority
+// - Emits an event for either outcome
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{InheritanceClaim, Vault};
+use crate::errors::VaultError;
+use crate::events::{InheritanceAuthorityClaimedEvent, InheritanceBalanceClaimedEvent};
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct ClaimInheritance<'info> {
+    #[account(mut)]
+    pub beneficiary: Signer<'info>,
+    #[account(
+        mut,
+        constraint = vault.beneficiary == Some(beneficiary.key()) @ VaultError::NotBeneficiary,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _claim_inheritance(ctx: Context<ClaimInheritance>, claim: InheritanceClaim) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    let beneficiary = &ctx.accounts.beneficiary;
+
+    // Check that the authority has been inactive long enough
+    let now = Clock::get()?.unix_timestamp;
+    let deadline = vault
+        .last_heartbeat
+        .checked_add(vault.inactivity_period)
+        .ok_or(VaultError::Overflow)?;
+    require!(now >= deadline, VaultError::AuthorityStillActive);
+
+    match claim {
+        InheritanceClaim::Balance => {
+            // Check if vault is locked or withdrawals are paused
+            require!(!vault.locked, VaultError::VaultLocked);
+            require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+            let vault_info = vault.to_account_info();
+            let rent = Rent::get()?.minimum_balance(vault_info.data_len());
+            let amount = vault_info.lamports().saturating_sub(rent);
+
+            // Transfer lamports from vault to beneficiary
+            transfer_from_program_account(&vault_info, &beneficiary.to_account_info(), amount)?;
+
+            emit!(InheritanceBalanceClaimedEvent {
+                amount,
+                beneficiary: beneficiary.key(),
+                vault: vault.key(),
+            });
+        }
+        InheritanceClaim::Authority => {
+            let previous_authority = vault.vault_authority;
+            vault.vault_authority = beneficiary.key();
+            vault.beneficiary = None;
+            vault.heartbeat()?;
+
+            emit!(InheritanceAuthorityClaimedEvent {
+                vault: vault.key(),
+                previous_authority,
+                new_authority: beneficiary.key(),
+            });
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+//-------------------------------------------------------------------------------
+//
+// Create Pull Authorization Instruction
+//
+// - Only the vault authority can authorize a merchant
+// - Creates a pull authorization PDA for a fixed amount per period
+// - The first pull is available right away
+// - Emits a create pull authorization event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PullAuthorization, Vault};
+use crate::errors::VaultError;
+use crate::events::CreatePullAuthorizationEvent;
+
+#[derive(Accounts)]
+#[instruction(merchant: Pubkey)]
+pub struct CreatePullAuthorization<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        // space = discriminant + account size
+        space = 8 + PullAuthorization::INIT_SPACE,
+        seeds = [b"pull", vault.key().as_ref(), merchant.as_ref()],
+        bump
+    )]
+    pub pull_authorization: Account<'info, PullAuthorization>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _create_pull_authorization(
+    ctx: Context<CreatePullAuthorization>,
+    merchant: Pubkey,
+    amount_per_period: u64,
+    period: i64,
+) -> Result<()> {
+    require!(period > 0, VaultError::InvalidPullPeriod);
+
+    ctx.accounts.vault.heartbeat()?;
+
+    let pull_authorization = &mut ctx.accounts.pull_authorization;
+    pull_authorization.vault = ctx.accounts.vault.key();
+    pull_authorization.merchant = merchant;
+    pull_authorization.amount_per_period = amount_per_period;
+    pull_authorization.period = period;
+    pull_authorization.next_pull_at = Clock::get()?.unix_timestamp;
+    pull_authorization.bump = ctx.bumps.pull_authorization;
+
+    emit!(CreatePullAuthorizationEvent {
+        vault: pull_authorization.vault,
+        merchant,
+        amount_per_period,
+        period,
+    });
+    Ok(())
+}
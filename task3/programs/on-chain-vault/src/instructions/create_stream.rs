@@ -0,0 +1,81 @@
+//-------------------------------------------------------------------------------
+//
+// Create Stream Instruction
+//
+// - Only the vault authority can open a stream from the vault
+// - Creates a stream PDA paying `rate_per_second` lamports to the recipient
+//   between `start` and `end`; `stream_id` tells a recipient's streams apart
+// - Lamports stay in the vault until the recipient withdraws them
+// - Emits a stream created event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::StreamCreatedEvent;
+
+#[derive(Accounts)]
+#[instruction(recipient: Pubkey, stream_id: u64)]
+pub struct CreateStream<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init,
+        payer = vault_authority,
+        // space = discriminant + account size
+        space = 8 + Stream::INIT_SPACE,
+        seeds = [
+            b"stream",
+            vault.key().as_ref(),
+            recipient.as_ref(),
+            stream_id.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub stream: Account<'info, Stream>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _create_stream(
+    ctx: Context<CreateStream>,
+    recipient: Pubkey,
+    stream_id: u64,
+    rate_per_second: u64,
+    start: i64,
+    end: i64,
+) -> Result<()> {
+    require!(end > start && rate_per_second > 0, VaultError::InvalidStream);
+
+    ctx.accounts.vault.heartbeat()?;
+
+    let stream = &mut ctx.accounts.stream;
+    stream.vault = ctx.accounts.vault.key();
+    stream.recipient = recipient;
+    stream.stream_id = stream_id;
+    stream.rate_per_second = rate_per_second;
+    stream.start = start;
+    stream.end = end;
+    stream.withdrawn = 0;
+    stream.bump = ctx.bumps.stream;
+
+    // Reject streams whose total could never be represented
+    stream.accrued(end)?;
+
+    emit!(StreamCreatedEvent {
+        stream: stream.key(),
+        vault: stream.vault,
+        recipient,
+        rate_per_second,
+        start,
+        end,
+    });
+    Ok(())
+}
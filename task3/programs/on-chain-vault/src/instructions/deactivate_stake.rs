@@ -0,0 +1,69 @@
+//-------------------------------------------------------------------------------
+//
+// Deactivate Stake Instruction
+//
+// - Only the vault authority can deactivate, and only while the vault is unlocked
+// - Deactivates the vault's stake account via CPI, signed by the vault PDA
+// - Emits a deactivate stake event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_stake_interface as stake;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::DeactivateStakeEvent;
+
+#[derive(Accounts)]
+pub struct DeactivateStake<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: owned and validated by the stake program
+    #[account(
+        mut,
+        seeds = [b"stake", vault.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    /// CHECK: address is checked
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+}
+
+pub fn _deactivate_stake(ctx: Context<DeactivateStake>) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    let vault = &ctx.accounts.vault;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    let creator = vault.creator;
+    let vault_seeds: &[&[u8]] = &[b"vault", creator.as_ref(), &[vault.bump]];
+
+    invoke_signed(
+        &stake::instruction::deactivate_stake(&ctx.accounts.stake_account.key(), &vault.key()),
+        &[
+            ctx.accounts.stake_account.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            vault.to_account_info(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    emit!(DeactivateStakeEvent {
+        vault: vault.key(),
+        stake_account: ctx.accounts.stake_account.key(),
+    });
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//-------------------------------------------------------------------------------
+//
+// Delegate Stake Instruction
+//
+// - Only the vault authority can stake, and only while the vault is unlocked
+// - Creates the vault's stake account (a PDA owned by the stake program)
+// - Moves lamports from the vault into the stake account
+// - Initializes the stake with the vault PDA as staker and withdrawer
+// - Delegates the stake to the given vote account via CPI
+// - Emits a delegate stake event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use solana_stake_interface::{self as stake, state::{Authorized, Lockup, StakeStateV2}};
+use anchor_lang::solana_program::system_instruction::create_account;
+use anchor_lang::solana_program::sysvar;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::DelegateStakeEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct DelegateStake<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: created in this instruction and owned by the stake program afterwards
+    #[account(
+        mut,
+        seeds = [b"stake", vault.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    /// CHECK: validated by the stake program
+    pub vote_account: UncheckedAccount<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    /// CHECK: address is checked
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    /// CHECK: address is checked
+    #[account(address = stake::config::ID)]
+    pub stake_config: UncheckedAccount<'info>,
+    /// CHECK: address is checked
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _delegate_stake(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    let vault = &ctx.accounts.vault;
+    let stake_account = &ctx.accounts.stake_account;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    // Verify that the vault has enough balance to stake
+    let vault_balance = vault.to_account_info().lamports();
+    require_gte!(vault_balance, amount, VaultError::InsufficientBalance);
+
+    let vault_key = vault.key();
+    let stake_seeds: &[&[u8]] = &[b"stake", vault_key.as_ref(), &[ctx.bumps.stake_account]];
+    let creator = vault.creator;
+    let vault_seeds: &[&[u8]] = &[b"vault", creator.as_ref(), &[vault.bump]];
+
+    // Create the stake account, rent paid by the vault authority
+    let space = StakeStateV2::size_of();
+    invoke_signed(
+        &create_account(
+            &ctx.accounts.vault_authority.key(),
+            &stake_account.key(),
+            Rent::get()?.minimum_balance(space),
+            space as u64,
+            &stake::program::ID,
+        ),
+        &[
+            ctx.accounts.vault_authority.to_account_info(),
+            stake_account.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[stake_seeds],
+    )?;
+
+    // Move the staked lamports from vault to stake account
+    transfer_from_program_account(&vault.to_account_info(), &stake_account.to_account_info(), amount)?;
+
+    invoke(
+        &stake::instruction::initialize(
+            &stake_account.key(),
+            &Authorized {
+                staker: vault_key,
+                withdrawer: vault_key,
+            },
+            &Lockup::default(),
+        ),
+        &[
+            stake_account.to_account_info(),
+            ctx.accounts.rent.to_account_info(),
+        ],
+    )?;
+
+    invoke_signed(
+        &stake::instruction::delegate_stake(
+            &stake_account.key(),
+            &vault_key,
+            &ctx.accounts.vote_account.key(),
+        ),
+        &[
+            stake_account.to_account_info(),
+            ctx.accounts.vote_account.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.stake_history.to_account_info(),
+            ctx.accounts.stake_config.to_account_info(),
+            vault.to_account_info(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    emit!(DelegateStakeEvent {
+        amount,
+        vault: vault_key,
+        stake_account: stake_account.key(),
+        vote_account: ctx.accounts.vote_account.key(),
+    });
+    Ok(())
+}
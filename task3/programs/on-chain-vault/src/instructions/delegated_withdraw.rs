@@ -0,0 +1,73 @@
+//-------------------------------------------------------------------------------
+//
+// Delegated Withdraw Instruction
+//
+// - Only an approved delegate can withdraw, and only before the expiry
+// - Verify that the vault is not locked
+// - Verify that the amount fits in the remaining allowance and vault balance
+// - Transfer lamports from vault to delegate and reduce the allowance
+// - Emit a delegated withdraw event naming the delegate
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Delegate, Vault};
+use crate::errors::VaultError;
+use crate::events::DelegatedWithdrawEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct DelegatedWithdraw<'info> {
+    #[account(mut)]
+    pub delegate: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        has_one = vault, // Ensure the delegate belongs to this vault
+        has_one = delegate, // Ensure the signer is the approved delegate
+        seeds = [b"delegate", vault.key().as_ref(), delegate.key().as_ref()],
+        bump = delegate_account.bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _delegated_withdraw(ctx: Context<DelegatedWithdraw>, amount: u64) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let delegate_account = &mut ctx.accounts.delegate_account;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    // Check that the delegation is still valid and covers the amount
+    let now = Clock::get()?.unix_timestamp;
+    require!(now < delegate_account.expiry, VaultError::DelegateExpired);
+    require_gte!(delegate_account.allowance, amount, VaultError::AllowanceExceeded);
+
+    // Verify that the vault has enough balance to withdraw
+    let vault_balance = vault.to_account_info().lamports();
+    require_gte!(vault_balance, amount, VaultError::InsufficientBalance);
+
+    delegate_account.allowance -= amount;
+
+    // Transfer lamports from vault to delegate
+    transfer_from_program_account(
+        &vault.to_account_info(),
+        &ctx.accounts.delegate.to_account_info(),
+        amount,
+    )?;
+
+    emit!(DelegatedWithdrawEvent {
+        amount,
+        delegate: ctx.accounts.delegate.key(),
+        vault: vault.key(),
+        remaining_allowance: delegate_account.allowance,
+    });
+    Ok(())
+}
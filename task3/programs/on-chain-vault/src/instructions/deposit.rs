@@ -11,11 +11,10 @@
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::program::invoke;
-use anchor_lang::solana_program::system_instruction::transfer;
 use crate::state::Vault;
 use crate::errors::VaultError;
 use crate::events::DepositEvent;
+use crate::utils::transfer_from_signer;
 
 #[derive(Accounts)]
 pub struct Deposit<'info> {
@@ -27,32 +26,28 @@ pub struct Deposit<'info> {
 }
 
 pub fn _deposit(ctx: Context<Deposit>, amount: u64) -> Result<()> {
+    // A deposit signed by the vault authority also counts as a heartbeat
+    if ctx.accounts.user.key() == ctx.accounts.vault.vault_authority {
+        ctx.accounts.vault.heartbeat()?;
+    }
+
     let user = &ctx.accounts.user;
     let vault = &ctx.accounts.vault;
 
     // Check if the user has enough balance to deposit
     require!(user.lamports() >= amount, VaultError::InsufficientBalance);
     
-    // Check if vault is locked
+    // Check if vault is locked or deposits are paused
     require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.deposits_paused, VaultError::DepositsPaused);
 
     // Transfer lamports from user to vault
-    let transfer_instruction = transfer(
-        &user.key(),
-        &vault.key(),
+    transfer_from_signer(
+        &user.to_account_info(),
+        &vault.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
         amount,
-    );
-
-    match  invoke(&transfer_instruction, &[
-        user.to_account_info(),
-        vault.to_account_info(),
-        ctx.accounts.system_program.to_account_info(),
-    ]) {
-        Ok(_) => (),
-        Err(e) => return Err(e.into()),
-        
-    };
-
+    )?;
 
     emit!(DepositEvent {
         user: ctx.accounts.user.key(),
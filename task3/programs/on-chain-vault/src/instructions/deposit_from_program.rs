@@ -0,0 +1,81 @@
+//-------------------------------------------------------------------------------
+//
+// Deposit From Program Instruction (CPI entry point)
+//
+// Lamports can only be debited by the program that owns an account, so a
+// caller holding its funds in an account it owns (not a system account)
+// cannot use `deposit`, which needs a system transfer out of the source.
+//
+// Instead the caller moves the lamports itself into the deposit buffer, a
+// system-owned PDA of this program seeded by [b"deposit", vault, depositor],
+// and then invokes this instruction with `depositor` signing (a PDA of the
+// caller can sign with invoke_signed). Everything in the buffer is swept
+// into the vault, so the deposited amount is whatever actually arrived.
+//
+// Callers whose source is a system-owned PDA can keep using `deposit` with
+// the PDA as `user`, signing for it with invoke_signed.
+//
+// - Verify that the vault is not locked and deposits are not paused
+// - Transfer the whole buffer balance from buffer to vault
+// - Emit a deposit event naming the depositor
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_lang::solana_program::system_instruction::transfer;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::DepositEvent;
+
+#[derive(Accounts)]
+pub struct DepositFromProgram<'info> {
+    pub depositor: Signer<'info>,
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        seeds = [b"deposit", vault.key().as_ref(), depositor.key().as_ref()],
+        bump,
+    )]
+    pub deposit_buffer: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _deposit_from_program(ctx: Context<DepositFromProgram>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let deposit_buffer = &ctx.accounts.deposit_buffer;
+
+    // Check if vault is locked or deposits are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.deposits_paused, VaultError::DepositsPaused);
+
+    let amount = deposit_buffer.lamports();
+    require!(amount > 0, VaultError::InsufficientBalance);
+
+    // Transfer lamports from deposit buffer to vault, emptying the buffer
+    let vault_key = vault.key();
+    let depositor_key = ctx.accounts.depositor.key();
+    let buffer_seeds: &[&[u8]] = &[
+        b"deposit",
+        vault_key.as_ref(),
+        depositor_key.as_ref(),
+        &[ctx.bumps.deposit_buffer],
+    ];
+    invoke_signed(
+        &transfer(&deposit_buffer.key(), &vault_key, amount),
+        &[
+            deposit_buffer.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+        &[buffer_seeds],
+    )?;
+
+    emit!(DepositEvent {
+        user: depositor_key,
+        vault: vault_key,
+        amount,
+    });
+    Ok(())
+}
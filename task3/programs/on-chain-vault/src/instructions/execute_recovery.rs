@@ -0,0 +1,78 @@
+//-------------------------------------------------------------------------------
+//
+// Execute Recovery Instruction
+//
+// - Anyone can execute once the threshold was reached and the waiting period
+//   is over, as long as enough approvers are still guardians
+// - Proposals made against an earlier authority can no longer execute
+// - Makes the proposed key the new vault authority
+// - Closes the proposal and returns its rent to the guardian who proposed it
+// - Emits a recovery executed event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{GuardianSet, RecoveryProposal, Vault};
+use crate::errors::VaultError;
+use crate::events::RecoveryExecutedEvent;
+
+#[derive(Accounts)]
+pub struct ExecuteRecovery<'info> {
+    pub executor: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        has_one = vault, // Ensure the guardian set belongs to this vault
+        seeds = [b"guardians", vault.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    /// CHECK: receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = proposer, // Close the proposal and return rent to the proposer
+        has_one = vault, // Ensure the proposal belongs to this vault
+        has_one = proposer, // Ensure the rent goes back to whoever paid it
+        seeds = [b"recovery", vault.key().as_ref(), proposer.key().as_ref()],
+        bump = recovery_proposal.bump,
+    )]
+    pub recovery_proposal: Account<'info, RecoveryProposal>,
+}
+
+pub fn _execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+    let guardian_set = &ctx.accounts.guardian_set;
+    let recovery_proposal = &ctx.accounts.recovery_proposal;
+
+    // Check that the waiting period is over and the approvals still hold
+    let now = Clock::get()?.unix_timestamp;
+    require!(
+        guardian_set.threshold > 0
+            && recovery_proposal.executable_at != 0
+            && now >= recovery_proposal.executable_at
+            && recovery_proposal.valid_approvals(guardian_set) >= guardian_set.threshold as usize,
+        VaultError::RecoveryNotReady
+    );
+    require_keys_eq!(
+        ctx.accounts.vault.vault_authority,
+        recovery_proposal.previous_authority,
+        VaultError::StaleRecovery
+    );
+
+    let vault = &mut ctx.accounts.vault;
+    let previous_authority = vault.vault_authority;
+    vault.vault_authority = recovery_proposal.new_authority;
+    vault.heartbeat()?;
+
+    emit!(RecoveryExecutedEvent {
+        vault: vault.key(),
+        previous_authority,
+        new_authority: recovery_proposal.new_authority,
+    });
+    Ok(())
+}
@@ -0,0 +1,73 @@
+//-------------------------------------------------------------------------------
+//
+// Fund Escrow Instruction
+//
+// - Creates an escrow PDA naming the depositor, payee and arbiter
+// - Transfers the escrowed amount from the depositor into the escrow
+// - Emits an escrow funded event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Escrow;
+use crate::errors::VaultError;
+use crate::events::EscrowFundedEvent;
+use crate::utils::transfer_from_signer;
+
+#[derive(Accounts)]
+#[instruction(seed: u64)]
+pub struct FundEscrow<'info> {
+    #[account(mut)]
+    pub depositor: Signer<'info>,
+    #[account(
+        init,
+        payer = depositor,
+        // space = discriminant + account size
+        space = 8 + Escrow::INIT_SPACE,
+        seeds = [b"escrow", depositor.key().as_ref(), seed.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub escrow: Account<'info, Escrow>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _fund_escrow(
+    ctx: Context<FundEscrow>,
+    seed: u64,
+    payee: Pubkey,
+    arbiter: Pubkey,
+    amount: u64,
+    expiry: i64,
+) -> Result<()> {
+    let depositor = &ctx.accounts.depositor;
+
+    // Check if the depositor has enough balance to fund the escrow
+    require!(depositor.lamports() >= amount, VaultError::InsufficientBalance);
+
+    let escrow = &mut ctx.accounts.escrow;
+    escrow.depositor = depositor.key();
+    escrow.payee = payee;
+    escrow.arbiter = arbiter;
+    escrow.amount = amount;
+    escrow.expiry = expiry;
+    escrow.seed = seed;
+    escrow.bump = ctx.bumps.escrow;
+
+    // Transfer lamports from depositor to escrow
+    transfer_from_signer(
+        &depositor.to_account_info(),
+        &escrow.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        amount,
+    )?;
+
+    emit!(EscrowFundedEvent {
+        escrow: escrow.key(),
+        depositor: escrow.depositor,
+        payee,
+        arbiter,
+        amount,
+        expiry,
+    });
+    Ok(())
+}
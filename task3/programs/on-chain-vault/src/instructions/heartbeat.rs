@@ -0,0 +1,27 @@
+//-------------------------------------------------------------------------------
+//
+// Heartbeat Instruction
+//
+// - Only the vault authority can send a heartbeat
+// - Does nothing except record that the authority is still active
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+
+#[derive(Accounts)]
+pub struct Heartbeat<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+    ctx.accounts.vault.heartbeat()
+}
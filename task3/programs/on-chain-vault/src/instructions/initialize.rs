@@ -39,7 +39,12 @@ pub fn _init_vault(ctx: Context<InitializeVault>, locked: bool) -> Result<()> {
   let vault = &mut ctx.accounts.vault;
 
   vault.vault_authority = ctx.accounts.vault_authority.key();
-  vault.locked = locked;
+  vault.creator = vault.vault_authority;
+  vault.set_paused(locked, locked);
+  vault.beneficiary = None;
+  vault.inactivity_period = 0;
+  vault.heartbeat()?;
+  vault.bump = ctx.bumps.vault;
 
   emit!(InitializeVaultEvent {
     vault: vault.key(),
@@ -0,0 +1,95 @@
+//-------------------------------------------------------------------------------
+//
+// Migrate Vault Instruction
+//
+// - Vaults created before the creator, pause flags, inheritance settings and
+//   stored bump were added only hold the authority and the locked flag, so
+//   they no longer deserialize
+// - Only the vault authority can migrate its vault
+// - Grow the account to the current layout, topping up rent from the authority
+// - The authority is the creator the vault PDA was derived from; store its bump
+// - Carry the locked flag over to both pause flags
+// - Emit a migrate vault event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::MigrateVaultEvent;
+use crate::utils::transfer_from_signer;
+
+/// Vault layout before migration: the authority and the locked flag.
+#[derive(AnchorDeserialize)]
+struct LegacyVault {
+    vault_authority: Pubkey,
+    locked: bool,
+}
+
+#[derive(Accounts)]
+pub struct MigrateVault<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    /// CHECK: decoded in the legacy layout and checked against the authority's vault PDA
+    #[account(mut, owner = crate::ID)]
+    pub vault: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+    let vault_info = ctx.accounts.vault.to_account_info();
+    let authority = ctx.accounts.vault_authority.key();
+    let space = 8 + Vault::INIT_SPACE;
+    require!(vault_info.data_len() < space, VaultError::VaultUpToDate);
+
+    let legacy = {
+        let data = vault_info.try_borrow_data()?;
+        require!(
+            data.starts_with(Vault::DISCRIMINATOR),
+            ErrorCode::AccountDiscriminatorMismatch
+        );
+        LegacyVault::deserialize(&mut &data[8..])?
+    };
+    require_keys_eq!(legacy.vault_authority, authority, ErrorCode::ConstraintHasOne);
+
+    // Legacy vaults were always seeded by their authority
+    let (address, bump) = Pubkey::find_program_address(&[b"vault", authority.as_ref()], ctx.program_id);
+    require_keys_eq!(address, vault_info.key(), ErrorCode::ConstraintSeeds);
+
+    let top_up = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(vault_info.lamports());
+    if top_up > 0 {
+        transfer_from_signer(
+            &ctx.accounts.vault_authority.to_account_info(),
+            &vault_info,
+            &ctx.accounts.system_program.to_account_info(),
+            top_up,
+        )?;
+    }
+    vault_info.resize(space)?;
+
+    let mut vault = Vault {
+        vault_authority: authority,
+        locked: false,
+        deposits_paused: false,
+        withdrawals_paused: false,
+        creator: authority,
+        beneficiary: None,
+        inactivity_period: 0,
+        last_heartbeat: 0,
+        bump,
+    };
+    vault.set_paused(legacy.locked, legacy.locked);
+    vault.heartbeat()?;
+    vault.try_serialize(&mut &mut vault_info.try_borrow_mut_data()?[..])?;
+
+    emit!(MigrateVaultEvent {
+        vault: vault_info.key(),
+        vault_authority: authority,
+        locked: vault.locked,
+    });
+
+    Ok(())
+}
@@ -2,8 +2,66 @@ mod initialize;
 mod deposit;
 mod withdraw;
 mod toggle_lock;
+mod approve_delegate;
+mod revoke_delegate;
+mod delegated_withdraw;
+mod delegate_stake;
+mod deactivate_stake;
+mod withdraw_stake;
+mod set_beneficiary;
+mod heartbeat;
+mod claim_inheritance;
+mod fund_escrow;
+mod release_escrow;
+mod refund_escrow;
+mod timeout_refund_escrow;
+mod create_stream;
+mod withdraw_from_stream;
+mod cancel_stream;
+mod create_pull_authorization;
+mod revoke_pull_authorization;
+mod pull;
+mod set_deposit_pause;
+mod set_withdraw_pause;
+mod deposit_from_program;
+mod set_guardians;
+mod propose_recovery;
+mod approve_recovery;
+mod veto_recovery;
+mod cancel_recovery;
+mod execute_recovery;
+mod migrate_vault;
 
 pub use initialize::*;
 pub use deposit::*;
 pub use withdraw::*;
-pub use toggle_lock::*;
\ No newline at end of file
+pub use toggle_lock::*;
+pub use approve_delegate::*;
+pub use revoke_delegate::*;
+pub use delegated_withdraw::*;
+pub use delegate_stake::*;
+pub use deactivate_stake::*;
+pub use withdraw_stake::*;
+pub use set_beneficiary::*;
+pub use heartbeat::*;
+pub use claim_inheritance::*;
+pub use fund_escrow::*;
+pub use release_escrow::*;
+pub use refund_escrow::*;
+pub use timeout_refund_escrow::*;
+pub use create_stream::*;
+pub use withdraw_from_stream::*;
+pub use cancel_stream::*;
+pub use create_pull_authorization::*;
+pub use revoke_pull_authorization::*;
+pub use pull::*;
+pub use set_deposit_pause::*;
+pub use set_withdraw_pause::*;
+pub use deposit_from_program::*;
+pub use set_guardians::*;
+pub use propose_recovery::*;
+pub use approve_recovery::*;
+pub use veto_recovery::*;
+pub use cancel_recovery::*;
+pub use execute_recovery::*;
+pub use migrate_vault::*;
@@ -0,0 +1,78 @@
+//-------------------------------------------------------------------------------
+//
+// Propose Recovery Instruction
+//
+// - Only a guardian can propose a new vault authority
+// - Creates the recovery proposal PDA, one per vault and proposer, approved by
+//   the proposer; a stuck proposal never blocks other guardians
+// - Starts the waiting period right away if the threshold is a single guardian
+// - Emits a recovery proposed event (and an approval event for the proposer)
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{GuardianSet, RecoveryProposal, Vault};
+use crate::errors::VaultError;
+use crate::events::{RecoveryApprovedEvent, RecoveryProposedEvent};
+
+#[derive(Accounts)]
+pub struct ProposeRecovery<'info> {
+    #[account(mut)]
+    pub guardian: Signer<'info>,
+    #[account(
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        has_one = vault, // Ensure the guardian set belongs to this vault
+        constraint = guardian_set.guardians.contains(&guardian.key()) @ VaultError::NotGuardian,
+        seeds = [b"guardians", vault.key().as_ref()],
+        bump = guardian_set.bump,
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    #[account(
+        init,
+        payer = guardian,
+        // space = discriminant + account size
+        space = 8 + RecoveryProposal::INIT_SPACE,
+        seeds = [b"recovery", vault.key().as_ref(), guardian.key().as_ref()],
+        bump
+    )]
+    pub recovery_proposal: Account<'info, RecoveryProposal>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _propose_recovery(ctx: Context<ProposeRecovery>, new_authority: Pubkey) -> Result<()> {
+    let guardian_set = &ctx.accounts.guardian_set;
+    let guardian = ctx.accounts.guardian.key();
+
+    let recovery_proposal = &mut ctx.accounts.recovery_proposal;
+    recovery_proposal.vault = ctx.accounts.vault.key();
+    recovery_proposal.proposer = guardian;
+    recovery_proposal.previous_authority = ctx.accounts.vault.vault_authority;
+    recovery_proposal.new_authority = new_authority;
+    recovery_proposal.approvals = vec![guardian];
+    recovery_proposal.executable_at = 0;
+    recovery_proposal.bump = ctx.bumps.recovery_proposal;
+
+    if guardian_set.threshold == 1 {
+        recovery_proposal.executable_at = Clock::get()?
+            .unix_timestamp
+            .checked_add(guardian_set.recovery_delay)
+            .ok_or(VaultError::Overflow)?;
+    }
+
+    emit!(RecoveryProposedEvent {
+        vault: recovery_proposal.vault,
+        proposer: guardian,
+        new_authority,
+    });
+    emit!(RecoveryApprovedEvent {
+        vault: recovery_proposal.vault,
+        guardian,
+        approvals: 1,
+        executable_at: recovery_proposal.executable_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,76 @@
+//-------------------------------------------------------------------------------
+//
+// Pull Instruction
+//
+// - Only the authorized merchant can pull, at most once per period
+// - Verify that the vault is not locked and can cover the amount
+// - Transfer the amount per period from vault to merchant
+// - Move next_pull_at to the start of the next period; periods the
+//   merchant skipped are not pulled later
+// - Emit a pull event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PullAuthorization, Vault};
+use crate::errors::VaultError;
+use crate::events::PullEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct Pull<'info> {
+    #[account(mut)]
+    pub merchant: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        has_one = vault, // Ensure the authorization belongs to this vault
+        has_one = merchant, // Ensure the signer is the authorized merchant
+        seeds = [b"pull", vault.key().as_ref(), merchant.key().as_ref()],
+        bump = pull_authorization.bump,
+    )]
+    pub pull_authorization: Account<'info, PullAuthorization>,
+}
+
+pub fn _pull(ctx: Context<Pull>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let pull_authorization = &mut ctx.accounts.pull_authorization;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    // Check that this period has not been pulled yet
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= pull_authorization.next_pull_at, VaultError::PullTooEarly);
+
+    let amount = pull_authorization.amount_per_period;
+    let vault_balance = vault.to_account_info().lamports();
+    require_gte!(vault_balance, amount, VaultError::InsufficientBalance);
+
+    let periods = (now - pull_authorization.next_pull_at) / pull_authorization.period + 1;
+    pull_authorization.next_pull_at = periods
+        .checked_mul(pull_authorization.period)
+        .and_then(|delta| pull_authorization.next_pull_at.checked_add(delta))
+        .ok_or(VaultError::Overflow)?;
+
+    // Transfer lamports from vault to merchant
+    transfer_from_program_account(
+        &vault.to_account_info(),
+        &ctx.accounts.merchant.to_account_info(),
+        amount,
+    )?;
+
+    emit!(PullEvent {
+        amount,
+        vault: vault.key(),
+        merchant: pull_authorization.merchant,
+        next_pull_at: pull_authorization.next_pull_at,
+    });
+    Ok(())
+}
@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+//
+// Refund Escrow Instruction
+//
+// - Only the payee or the arbiter can refund
+// - Returns the escrowed amount and the escrow rent to the depositor
+// - Emits an escrow refunded event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Escrow;
+use crate::errors::VaultError;
+use crate::events::EscrowRefundedEvent;
+
+#[derive(Accounts)]
+pub struct RefundEscrow<'info> {
+    #[account(
+        constraint = caller.key() == escrow.payee || caller.key() == escrow.arbiter
+            @ VaultError::UnauthorizedEscrowParty,
+    )]
+    pub caller: Signer<'info>,
+    /// CHECK: checked against the escrow depositor
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = depositor, // Closing returns the escrowed amount and rent to the depositor
+        has_one = depositor,
+        seeds = [b"escrow", depositor.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn _refund_escrow(ctx: Context<RefundEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    emit!(EscrowRefundedEvent {
+        escrow: escrow.key(),
+        depositor: escrow.depositor,
+        refunded_by: ctx.accounts.caller.key(),
+        amount: escrow.amount,
+    });
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//-------------------------------------------------------------------------------
+//
+// Release Escrow Instruction
+//
+// - Only the depositor or the arbiter can release
+// - Pays the escrowed amount to the payee
+// - Closes the escrow and returns its rent to the depositor
+// - Emits an escrow released event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Escrow;
+use crate::errors::VaultError;
+use crate::events::EscrowReleasedEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct ReleaseEscrow<'info> {
+    #[account(
+        constraint = caller.key() == escrow.depositor || caller.key() == escrow.arbiter
+            @ VaultError::UnauthorizedEscrowParty,
+    )]
+    pub caller: Signer<'info>,
+    /// CHECK: checked against the escrow depositor
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    /// CHECK: checked against the escrow payee
+    #[account(mut)]
+    pub payee: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = depositor, // Close the escrow account and return rent to the depositor
+        has_one = depositor,
+        has_one = payee,
+        seeds = [b"escrow", depositor.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn _release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    // Transfer the escrowed lamports from escrow to payee
+    transfer_from_program_account(
+        &escrow.to_account_info(),
+        &ctx.accounts.payee.to_account_info(),
+        escrow.amount,
+    )?;
+
+    emit!(EscrowReleasedEvent {
+        escrow: escrow.key(),
+        payee: escrow.payee,
+        released_by: ctx.accounts.caller.key(),
+        amount: escrow.amount,
+    });
+    Ok(())
+}
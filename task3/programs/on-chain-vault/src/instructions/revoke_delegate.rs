@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+//
+// Revoke Delegate Instruction
+//
+// - Only the vault authority can revoke a delegate
+// - Closes the delegate PDA and returns its rent to the vault authority
+// - Emits a revoke delegate event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Delegate, Vault};
+use crate::events::RevokeDelegateEvent;
+
+#[derive(Accounts)]
+pub struct RevokeDelegate<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority, // Close the delegate account and return rent to the authority
+        has_one = vault, // Ensure the delegate belongs to this vault
+        seeds = [b"delegate", vault.key().as_ref(), delegate_account.delegate.as_ref()],
+        bump = delegate_account.bump,
+    )]
+    pub delegate_account: Account<'info, Delegate>,
+}
+
+pub fn _revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    emit!(RevokeDelegateEvent {
+        vault: ctx.accounts.vault.key(),
+        vault_authority: ctx.accounts.vault_authority.key(),
+        delegate: ctx.accounts.delegate_account.delegate,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,44 @@
+//-------------------------------------------------------------------------------
+//
+// Revoke Pull Authorization Instruction
+//
+// - Only the vault authority can revoke a merchant
+// - Closes the pull authorization and returns its rent to the vault authority
+// - Emits a revoke pull authorization event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{PullAuthorization, Vault};
+use crate::events::RevokePullAuthorizationEvent;
+
+#[derive(Accounts)]
+pub struct RevokePullAuthorization<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        close = vault_authority, // Close the authorization and return rent to the authority
+        has_one = vault, // Ensure the authorization belongs to this vault
+        seeds = [b"pull", vault.key().as_ref(), pull_authorization.merchant.as_ref()],
+        bump = pull_authorization.bump,
+    )]
+    pub pull_authorization: Account<'info, PullAuthorization>,
+}
+
+pub fn _revoke_pull_authorization(ctx: Context<RevokePullAuthorization>) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    emit!(RevokePullAuthorizationEvent {
+        vault: ctx.accounts.vault.key(),
+        merchant: ctx.accounts.pull_authorization.merchant,
+    });
+    Ok(())
+}
@@ -0,0 +1,59 @@
+//-------------------------------------------------------------------------------
+//
+// Set Beneficiary Instruction
+//
+// - Only the vault authority can set or clear the beneficiary
+// - The inactivity period must be positive when setting a beneficiary and is
+//   reset to zero when clearing it
+// - The beneficiary can claim the vault once the authority has been inactive
+//   for longer than the inactivity period
+// - Emits a set beneficiary event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::SetBeneficiaryEvent;
+
+#[derive(Accounts)]
+pub struct SetBeneficiary<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_beneficiary(
+    ctx: Context<SetBeneficiary>,
+    beneficiary: Option<Pubkey>,
+    inactivity_period: i64,
+) -> Result<()> {
+    // The period only matters while a beneficiary is set; clearing resets it
+    let inactivity_period = match beneficiary {
+        Some(_) => {
+            require!(inactivity_period > 0, VaultError::InvalidInactivityPeriod);
+            inactivity_period
+        }
+        None => 0,
+    };
+
+    let vault = &mut ctx.accounts.vault;
+    vault.heartbeat()?;
+    vault.beneficiary = beneficiary;
+    vault.inactivity_period = inactivity_period;
+
+    emit!(SetBeneficiaryEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        beneficiary,
+        inactivity_period,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+//
+// Set Deposit Pause Instruction
+//
+// - Only the vault authority can pause or resume deposits
+// - Withdrawals are left as they are
+// - Emits a deposit pause event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::DepositPauseEvent;
+
+#[derive(Accounts)]
+pub struct SetDepositPause<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_deposit_pause(ctx: Context<SetDepositPause>, paused: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.heartbeat()?;
+
+    let withdrawals_paused = vault.withdrawals_paused;
+    vault.set_paused(paused, withdrawals_paused);
+
+    emit!(DepositPauseEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        paused,
+    });
+
+    Ok(())
+}
@@ -0,0 +1,79 @@
+//-------------------------------------------------------------------------------
+//
+// Set Guardians Instruction
+//
+// - Only the vault authority can set the guardian set
+// - Creates the guardian set PDA on first use and replaces it afterwards
+// - Guardians must be unique and the threshold between 1 and their count;
+//   an empty set with a zero threshold turns recovery off
+// - Approvals on a pending recovery only count while the guardian stays in the set
+// - Emits a set guardians event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{GuardianSet, Vault, MAX_GUARDIANS};
+use crate::errors::VaultError;
+use crate::events::SetGuardiansEvent;
+
+#[derive(Accounts)]
+pub struct SetGuardians<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        init_if_needed,
+        payer = vault_authority,
+        // space = discriminant + account size
+        space = 8 + GuardianSet::INIT_SPACE,
+        seeds = [b"guardians", vault.key().as_ref()],
+        bump
+    )]
+    pub guardian_set: Account<'info, GuardianSet>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn _set_guardians(
+    ctx: Context<SetGuardians>,
+    guardians: Vec<Pubkey>,
+    threshold: u8,
+    recovery_delay: i64,
+) -> Result<()> {
+    let unique = guardians
+        .iter()
+        .enumerate()
+        .all(|(i, guardian)| !guardians[..i].contains(guardian));
+    let valid_threshold = if guardians.is_empty() {
+        threshold == 0
+    } else {
+        threshold > 0 && threshold as usize <= guardians.len()
+    };
+    require!(
+        unique && guardians.len() <= MAX_GUARDIANS && valid_threshold,
+        VaultError::InvalidGuardianSet
+    );
+    require!(recovery_delay > 0, VaultError::InvalidRecoveryDelay);
+
+    ctx.accounts.vault.heartbeat()?;
+
+    let guardian_set = &mut ctx.accounts.guardian_set;
+    guardian_set.vault = ctx.accounts.vault.key();
+    guardian_set.guardians = guardians;
+    guardian_set.threshold = threshold;
+    guardian_set.recovery_delay = recovery_delay;
+    guardian_set.bump = ctx.bumps.guardian_set;
+
+    emit!(SetGuardiansEvent {
+        vault: guardian_set.vault,
+        guardians: guardian_set.guardians.clone(),
+        threshold,
+        recovery_delay,
+    });
+    Ok(())
+}
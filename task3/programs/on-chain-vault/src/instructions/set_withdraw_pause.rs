@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+//
+// Set Withdraw Pause Instruction
+//
+// - Only the vault authority can pause or resume withdrawals
+// - Pausing blocks every outflow from the vault; deposits are left as they are
+// - Emits a withdraw pause event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Vault;
+use crate::events::WithdrawPauseEvent;
+
+#[derive(Accounts)]
+pub struct SetWithdrawPause<'info> {
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+}
+
+pub fn _set_withdraw_pause(ctx: Context<SetWithdrawPause>, paused: bool) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.heartbeat()?;
+
+    let deposits_paused = vault.deposits_paused;
+    vault.set_paused(deposits_paused, paused);
+
+    emit!(WithdrawPauseEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        paused,
+    });
+
+    Ok(())
+}
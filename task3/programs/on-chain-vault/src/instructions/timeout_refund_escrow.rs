@@ -0,0 +1,46 @@
+//-------------------------------------------------------------------------------
+//
+// Timeout Refund Escrow Instruction
+//
+// - Anyone can trigger it, but only after the escrow has expired
+// - Returns the escrowed amount and the escrow rent to the depositor
+// - Emits an escrow refunded event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::Escrow;
+use crate::errors::VaultError;
+use crate::events::EscrowRefundedEvent;
+
+#[derive(Accounts)]
+pub struct TimeoutRefundEscrow<'info> {
+    pub caller: Signer<'info>,
+    /// CHECK: checked against the escrow depositor
+    #[account(mut)]
+    pub depositor: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = depositor, // Closing returns the escrowed amount and rent to the depositor
+        has_one = depositor,
+        seeds = [b"escrow", depositor.key().as_ref(), escrow.seed.to_le_bytes().as_ref()],
+        bump = escrow.bump,
+    )]
+    pub escrow: Account<'info, Escrow>,
+}
+
+pub fn _timeout_refund_escrow(ctx: Context<TimeoutRefundEscrow>) -> Result<()> {
+    let escrow = &ctx.accounts.escrow;
+
+    // Check that the escrow has expired
+    let now = Clock::get()?.unix_timestamp;
+    require!(now >= escrow.expiry, VaultError::EscrowNotExpired);
+
+    emit!(EscrowRefundedEvent {
+        escrow: escrow.key(),
+        depositor: escrow.depositor,
+        refunded_by: ctx.accounts.caller.key(),
+        amount: escrow.amount,
+    });
+    Ok(())
+}
@@ -20,8 +20,8 @@ pub struct ToggleLock<'info> {
     #[account(
         mut,
         has_one = vault_authority, // Ensure the vault authority matches
-        seeds = [b"vault", vault_authority.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
     )]
     pub vault: Account<'info, Vault>,
     pub system_program: Program<'info, System>,
@@ -29,9 +29,11 @@ pub struct ToggleLock<'info> {
 
 pub fn _toggle_lock(ctx: Context<ToggleLock>) -> Result<()> {
     let vault = &mut ctx.accounts.vault;
+    vault.heartbeat()?;
 
-    // Toggle the locked state
-    vault.locked = !vault.locked;
+    // Toggle the locked state, pausing or resuming deposits and withdrawals together
+    let locked = !vault.locked;
+    vault.set_paused(locked, locked);
 
     emit!(ToggleLockEvent {
         vault: vault.key(),
@@ -0,0 +1,50 @@
+//-------------------------------------------------------------------------------
+//
+// Veto Recovery Instruction
+//
+// - Only the current vault authority can veto a pending recovery
+// - Closes the proposal and returns its rent to the guardian who proposed it
+// - Emits a recovery vetoed event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{RecoveryProposal, Vault};
+use crate::events::RecoveryVetoedEvent;
+
+#[derive(Accounts)]
+pub struct VetoRecovery<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: receives the proposal rent, checked against the proposal
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+    #[account(
+        mut,
+        close = proposer, // Close the proposal and return rent to the proposer
+        has_one = vault, // Ensure the proposal belongs to this vault
+        has_one = proposer, // Ensure the rent goes back to whoever paid it
+        seeds = [b"recovery", vault.key().as_ref(), proposer.key().as_ref()],
+        bump = recovery_proposal.bump,
+    )]
+    pub recovery_proposal: Account<'info, RecoveryProposal>,
+}
+
+pub fn _veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+    let vault = &mut ctx.accounts.vault;
+    vault.heartbeat()?;
+
+    emit!(RecoveryVetoedEvent {
+        vault: vault.key(),
+        vault_authority: vault.vault_authority,
+        new_authority: ctx.accounts.recovery_proposal.new_authority,
+    });
+    Ok(())
+}
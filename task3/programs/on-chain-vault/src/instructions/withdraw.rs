@@ -2,6 +2,7 @@
 use crate::errors::VaultError;
 use crate::events::WithdrawEvent;
 use crate::state::Vault;
+use crate::utils::transfer_from_program_account;
 ///
 /// TASK: Implement the withdraw functionality for the on-chain vault
 ///
@@ -21,35 +22,34 @@ pub struct Withdraw<'info> {
     #[account(
         mut,
         has_one = vault_authority, // Ensure the vault authority matches
-        seeds = [b"vault", vault_authority.key().as_ref()],
-        bump,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
     )]
     pub vault: Account<'info, Vault>,
     pub system_program: Program<'info, System>,
 }
 
 pub fn _withdraw(ctx: Context<Withdraw>, amount: u64) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
     // let user = &ctx.accounts.vault_authority;
     let vault = &ctx.accounts.vault;
 
-    // Check if vault is locked
+    // Check if vault is locked or withdrawals are paused
     require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
 
     // Verify that the vault has enough balance to withdraw
     let vault_balance = vault.to_account_info().lamports();
     require_gte!(vault_balance, amount, VaultError::InsufficientBalance);
 
-    // Transfer lamports from vault to vault authority using invoke_signed
-    **ctx
-        .accounts
-        .vault
-        .to_account_info()
-        .try_borrow_mut_lamports()? -= amount;
-    **ctx
-        .accounts
-        .vault_authority
-        .to_account_info()
-        .try_borrow_mut_lamports()? += amount;
+    // Transfer lamports from vault to vault authority
+    transfer_from_program_account(
+        &vault.to_account_info(),
+        &ctx.accounts.vault_authority.to_account_info(),
+        amount,
+    )?;
+
     emit!(WithdrawEvent {
         amount,
         vault_authority: vault.vault_authority,
@@ -0,0 +1,76 @@
+//-------------------------------------------------------------------------------
+//
+// Withdraw From Stream Instruction
+//
+// - Only the stream recipient can withdraw, and only while the vault is unlocked
+// - Transfers everything accrued and not yet withdrawn from vault to recipient
+// - Emits a stream withdraw event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use crate::state::{Stream, Vault};
+use crate::errors::VaultError;
+use crate::events::StreamWithdrawEvent;
+use crate::utils::transfer_from_program_account;
+
+#[derive(Accounts)]
+pub struct WithdrawFromStream<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    #[account(
+        mut,
+        has_one = vault, // Ensure the stream draws from this vault
+        has_one = recipient, // Ensure the signer is the stream recipient
+        seeds = [
+            b"stream",
+            vault.key().as_ref(),
+            recipient.key().as_ref(),
+            stream.stream_id.to_le_bytes().as_ref(),
+        ],
+        bump = stream.bump,
+    )]
+    pub stream: Account<'info, Stream>,
+}
+
+pub fn _withdraw_from_stream(ctx: Context<WithdrawFromStream>) -> Result<()> {
+    let vault = &ctx.accounts.vault;
+    let stream = &mut ctx.accounts.stream;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    let amount = stream.withdrawable(Clock::get()?.unix_timestamp)?;
+    require!(amount > 0, VaultError::NothingAccrued);
+
+    // Verify that the vault has enough balance to pay out
+    let vault_balance = vault.to_account_info().lamports();
+    require_gte!(vault_balance, amount, VaultError::InsufficientBalance);
+
+    stream.withdrawn = stream
+        .withdrawn
+        .checked_add(amount)
+        .ok_or(VaultError::Overflow)?;
+
+    // Transfer lamports from vault to recipient
+    transfer_from_program_account(
+        &vault.to_account_info(),
+        &ctx.accounts.recipient.to_account_info(),
+        amount,
+    )?;
+
+    emit!(StreamWithdrawEvent {
+        amount,
+        stream: stream.key(),
+        vault: vault.key(),
+        recipient: stream.recipient,
+    });
+    Ok(())
+}
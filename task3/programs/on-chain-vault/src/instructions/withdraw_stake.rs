@@ -0,0 +1,82 @@
+//-------------------------------------------------------------------------------
+//
+// Withdraw Stake Instruction
+//
+// - Only the vault authority can withdraw stake, and only while the vault is unlocked
+// - Withdraws inactive lamports from the stake account back into the vault
+// - Emits a withdraw stake event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke_signed;
+use solana_stake_interface as stake;
+use anchor_lang::solana_program::sysvar;
+use crate::state::Vault;
+use crate::errors::VaultError;
+use crate::events::WithdrawStakeEvent;
+
+#[derive(Accounts)]
+pub struct WithdrawStake<'info> {
+    #[account(mut)]
+    pub vault_authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = vault_authority, // Ensure the vault authority matches
+        seeds = [b"vault", vault.creator.as_ref()],
+        bump = vault.bump,
+    )]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: owned and validated by the stake program
+    #[account(
+        mut,
+        seeds = [b"stake", vault.key().as_ref()],
+        bump,
+    )]
+    pub stake_account: UncheckedAccount<'info>,
+    pub clock: Sysvar<'info, Clock>,
+    /// CHECK: address is checked
+    #[account(address = sysvar::stake_history::ID)]
+    pub stake_history: UncheckedAccount<'info>,
+    /// CHECK: address is checked
+    #[account(address = stake::program::ID)]
+    pub stake_program: UncheckedAccount<'info>,
+}
+
+pub fn _withdraw_stake(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+    ctx.accounts.vault.heartbeat()?;
+
+    let vault = &ctx.accounts.vault;
+
+    // Check if vault is locked or withdrawals are paused
+    require!(!vault.locked, VaultError::VaultLocked);
+    require!(!vault.withdrawals_paused, VaultError::WithdrawalsPaused);
+
+    let creator = vault.creator;
+    let vault_seeds: &[&[u8]] = &[b"vault", creator.as_ref(), &[vault.bump]];
+
+    // The vault PDA is both the withdraw authority and the destination
+    invoke_signed(
+        &stake::instruction::withdraw(
+            &ctx.accounts.stake_account.key(),
+            &vault.key(),
+            &vault.key(),
+            amount,
+            None,
+        ),
+        &[
+            ctx.accounts.stake_account.to_account_info(),
+            vault.to_account_info(),
+            ctx.accounts.clock.to_account_info(),
+            ctx.accounts.stake_history.to_account_info(),
+        ],
+        &[vault_seeds],
+    )?;
+
+    emit!(WithdrawStakeEvent {
+        amount,
+        vault: vault.key(),
+        stake_account: ctx.accounts.stake_account.key(),
+    });
+    Ok(())
+}
@@ -25,11 +25,13 @@
 
 use anchor_lang::prelude::*;
 mod instructions;
-mod state;
-mod errors;
-mod events;
+pub mod state;
+pub mod errors;
+pub mod events;
+mod utils;
 
 use instructions::*;
+use state::InheritanceClaim;
 
 declare_id!("ARmiAGe6oAEq5BKguHydD3zt2n5PkV2Q5PLA1McuMkJT");
 
@@ -52,4 +54,153 @@ pub mod on_chain_vault {
     pub fn toggle_lock(ctx: Context<ToggleLock>) -> Result<()> {
       _toggle_lock(ctx)
     }
+
+    pub fn approve_delegate(
+      ctx: Context<ApproveDelegate>,
+      delegate: Pubkey,
+      allowance: u64,
+      expiry: i64,
+    ) -> Result<()> {
+      _approve_delegate(ctx, delegate, allowance, expiry)
+    }
+
+    pub fn revoke_delegate(ctx: Context<RevokeDelegate>) -> Result<()> {
+      _revoke_delegate(ctx)
+    }
+
+    pub fn delegated_withdraw(ctx: Context<DelegatedWithdraw>, amount: u64) -> Result<()> {
+      _delegated_withdraw(ctx, amount)
+    }
+
+    pub fn delegate_stake(ctx: Context<DelegateStake>, amount: u64) -> Result<()> {
+      _delegate_stake(ctx, amount)
+    }
+
+    pub fn deactivate_stake(ctx: Context<DeactivateStake>) -> Result<()> {
+      _deactivate_stake(ctx)
+    }
+
+    pub fn withdraw_stake(ctx: Context<WithdrawStake>, amount: u64) -> Result<()> {
+      _withdraw_stake(ctx, amount)
+    }
+
+    pub fn set_beneficiary(
+      ctx: Context<SetBeneficiary>,
+      beneficiary: Option<Pubkey>,
+      inactivity_period: i64,
+    ) -> Result<()> {
+      _set_beneficiary(ctx, beneficiary, inactivity_period)
+    }
+
+    pub fn heartbeat(ctx: Context<Heartbeat>) -> Result<()> {
+      _heartbeat(ctx)
+    }
+
+    pub fn claim_inheritance(ctx: Context<ClaimInheritance>, claim: InheritanceClaim) -> Result<()> {
+      _claim_inheritance(ctx, claim)
+    }
+
+    pub fn fund_escrow(
+      ctx: Context<FundEscrow>,
+      seed: u64,
+      payee: Pubkey,
+      arbiter: Pubkey,
+      amount: u64,
+      expiry: i64,
+    ) -> Result<()> {
+      _fund_escrow(ctx, seed, payee, arbiter, amount, expiry)
+    }
+
+    pub fn release_escrow(ctx: Context<ReleaseEscrow>) -> Result<()> {
+      _release_escrow(ctx)
+    }
+
+    pub fn refund_escrow(ctx: Context<RefundEscrow>) -> Result<()> {
+      _refund_escrow(ctx)
+    }
+
+    pub fn timeout_refund_escrow(ctx: Context<TimeoutRefundEscrow>) -> Result<()> {
+      _timeout_refund_escrow(ctx)
+    }
+
+    pub fn create_stream(
+      ctx: Context<CreateStream>,
+      recipient: Pubkey,
+      stream_id: u64,
+      rate_per_second: u64,
+      start: i64,
+      end: i64,
+    ) -> Result<()> {
+      _create_stream(ctx, recipient, stream_id, rate_per_second, start, end)
+    }
+
+    pub fn withdraw_from_stream(ctx: Context<WithdrawFromStream>) -> Result<()> {
+      _withdraw_from_stream(ctx)
+    }
+
+    pub fn cancel_stream(ctx: Context<CancelStream>) -> Result<()> {
+      _cancel_stream(ctx)
+    }
+
+    pub fn create_pull_authorization(
+      ctx: Context<CreatePullAuthorization>,
+      merchant: Pubkey,
+      amount_per_period: u64,
+      period: i64,
+    ) -> Result<()> {
+      _create_pull_authorization(ctx, merchant, amount_per_period, period)
+    }
+
+    pub fn revoke_pull_authorization(ctx: Context<RevokePullAuthorization>) -> Result<()> {
+      _revoke_pull_authorization(ctx)
+    }
+
+    pub fn pull(ctx: Context<Pull>) -> Result<()> {
+      _pull(ctx)
+    }
+
+    pub fn set_deposit_pause(ctx: Context<SetDepositPause>, paused: bool) -> Result<()> {
+      _set_deposit_pause(ctx, paused)
+    }
+
+    pub fn set_withdraw_pause(ctx: Context<SetWithdrawPause>, paused: bool) -> Result<()> {
+      _set_withdraw_pause(ctx, paused)
+    }
+
+    pub fn deposit_from_program(ctx: Context<DepositFromProgram>) -> Result<()> {
+      _deposit_from_program(ctx)
+    }
+
+    pub fn set_guardians(
+      ctx: Context<SetGuardians>,
+      guardians: Vec<Pubkey>,
+      threshold: u8,
+      recovery_delay: i64,
+    ) -> Result<()> {
+      _set_guardians(ctx, guardians, threshold, recovery_delay)
+    }
+
+    pub fn propose_recovery(ctx: Context<ProposeRecovery>, new_authority: Pubkey) -> Result<()> {
+      _propose_recovery(ctx, new_authority)
+    }
+
+    pub fn approve_recovery(ctx: Context<ApproveRecovery>) -> Result<()> {
+      _approve_recovery(ctx)
+    }
+
+    pub fn veto_recovery(ctx: Context<VetoRecovery>) -> Result<()> {
+      _veto_recovery(ctx)
+    }
+
+    pub fn cancel_recovery(ctx: Context<CancelRecovery>) -> Result<()> {
+      _cancel_recovery(ctx)
+    }
+
+    pub fn execute_recovery(ctx: Context<ExecuteRecovery>) -> Result<()> {
+      _execute_recovery(ctx)
+    }
+
+    pub fn migrate_vault(ctx: Context<MigrateVault>) -> Result<()> {
+      _migrate_vault(ctx)
+    }
 }
@@ -1,8 +1,143 @@
 use anchor_lang::prelude::*;
+use crate::errors::VaultError;
 
 #[account]
 #[derive(InitSpace)]
 pub struct Vault {
     pub vault_authority: Pubkey,
+    // Set exactly when both deposits and withdrawals are paused
     pub locked: bool,
+    pub deposits_paused: bool,
+    pub withdrawals_paused: bool,
+    // Key the vault PDA is derived from; stays fixed when the authority changes
+    pub creator: Pubkey,
+    pub beneficiary: Option<Pubkey>,
+    pub inactivity_period: i64,
+    pub last_heartbeat: i64,
+    pub bump: u8,
+}
+
+impl Vault {
+    /// Sets both pause flags and keeps the legacy `locked` flag in sync.
+    pub fn set_paused(&mut self, deposits: bool, withdrawals: bool) {
+        self.deposits_paused = deposits;
+        self.withdrawals_paused = withdrawals;
+        self.locked = deposits && withdrawals;
+    }
+
+    /// Records that the authority is still active.
+    pub fn heartbeat(&mut self) -> Result<()> {
+        self.last_heartbeat = Clock::get()?.unix_timestamp;
+        Ok(())
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq)]
+pub enum InheritanceClaim {
+    Balance,
+    Authority,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Delegate {
+    pub vault: Pubkey,
+    pub delegate: Pubkey,
+    pub allowance: u64,
+    pub expiry: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Escrow {
+    pub depositor: Pubkey,
+    pub payee: Pubkey,
+    pub arbiter: Pubkey,
+    pub amount: u64,
+    pub expiry: i64,
+    pub seed: u64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Stream {
+    pub vault: Pubkey,
+    pub recipient: Pubkey,
+    // Lets one recipient hold several streams from the same vault
+    pub stream_id: u64,
+    pub rate_per_second: u64,
+    pub start: i64,
+    pub end: i64,
+    pub withdrawn: u64,
+    pub bump: u8,
+}
+
+impl Stream {
+    /// Lamports the stream has accrued by `now`, including withdrawn ones.
+    pub fn accrued(&self, now: i64) -> Result<u64> {
+        let elapsed = now
+            .clamp(self.start, self.end)
+            .checked_sub(self.start)
+            .ok_or(error!(VaultError::Overflow))?;
+        self.rate_per_second
+            .checked_mul(u64::try_from(elapsed).map_err(|_| error!(VaultError::Overflow))?)
+            .ok_or(error!(VaultError::Overflow))
+    }
+
+    /// Lamports the recipient can withdraw at `now`.
+    pub fn withdrawable(&self, now: i64) -> Result<u64> {
+        Ok(self.accrued(now)?.saturating_sub(self.withdrawn))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PullAuthorization {
+    pub vault: Pubkey,
+    pub merchant: Pubkey,
+    pub amount_per_period: u64,
+    pub period: i64,
+    pub next_pull_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct GuardianSet {
+    pub vault: Pubkey,
+    #[max_len(MAX_GUARDIANS)]
+    pub guardians: Vec<Pubkey>,
+    // Guardian approvals needed before a recovery can start its waiting period
+    pub threshold: u8,
+    pub recovery_delay: i64,
+    pub bump: u8,
+}
+
+pub const MAX_GUARDIANS: usize = 10;
+
+#[account]
+#[derive(InitSpace)]
+pub struct RecoveryProposal {
+    pub vault: Pubkey,
+    pub proposer: Pubkey,
+    // Authority being replaced; the proposal goes stale once it changes
+    pub previous_authority: Pubkey,
+    pub new_authority: Pubkey,
+    #[max_len(MAX_GUARDIANS)]
+    pub approvals: Vec<Pubkey>,
+    // Zero until the threshold is reached, then the earliest execution time
+    pub executable_at: i64,
+    pub bump: u8,
+}
+
+impl RecoveryProposal {
+    /// Approvals that still come from a current guardian.
+    pub fn valid_approvals(&self, guardian_set: &GuardianSet) -> usize {
+        self.approvals
+            .iter()
+            .filter(|approval| guardian_set.guardians.contains(approval))
+            .count()
+    }
 }
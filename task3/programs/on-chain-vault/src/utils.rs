@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program::invoke;
+use anchor_lang::solana_program::system_instruction::transfer;
+
+/// Moves lamports out of a system-owned signer through a system program CPI.
+pub fn transfer_from_signer<'info>(
+    from: &AccountInfo<'info>,
+    to: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    invoke(
+        &transfer(from.key, to.key, amount),
+        &[from.clone(), to.clone(), system_program.clone()],
+    )?;
+    Ok(())
+}
+
+/// Moves lamports out of an account owned by this program by adjusting
+/// both balances directly. The caller checks that `from` can afford it.
+pub fn transfer_from_program_account(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
@@ -0,0 +1,137 @@
+#![allow(unexpected_cfgs)]
+
+//===============================================================================
+//
+// VAULT DEPOSITOR EXAMPLE
+//
+// Shows the two ways another program can deposit into an on-chain vault:
+//
+// - deposit_from_treasury: the source is a system-owned PDA of this program.
+//   It signs the vault's `deposit` instruction with invoke_signed and the
+//   vault moves the lamports with a system transfer.
+//
+// - deposit_from_ledger: the source is an account owned by this program,
+//   which the vault program cannot debit. This program moves the lamports
+//   directly into the vault's deposit buffer and then calls the vault's
+//   `deposit_from_program` entry point, signing as its authority PDA.
+//
+//===============================================================================
+
+use anchor_lang::prelude::*;
+use on_chain_vault::cpi::accounts::{Deposit, DepositFromProgram};
+use on_chain_vault::program::OnChainVault;
+use on_chain_vault::state::Vault;
+
+declare_id!("GZYSTgyu247ktQ4SauBkAquyQjuPTrGnZtwDQpvB4RMq");
+
+pub const TREASURY_SEED: &[u8] = b"treasury";
+pub const LEDGER_SEED: &[u8] = b"ledger";
+pub const AUTHORITY_SEED: &[u8] = b"authority";
+
+#[program]
+pub mod vault_depositor {
+    use super::*;
+
+    pub fn deposit_from_treasury(ctx: Context<DepositFromTreasury>, amount: u64) -> Result<()> {
+        let signer_seeds: &[&[u8]] = &[TREASURY_SEED, &[ctx.bumps.treasury]];
+
+        on_chain_vault::cpi::deposit(
+            CpiContext::new_with_signer(
+                ctx.accounts.vault_program.to_account_info(),
+                Deposit {
+                    user: ctx.accounts.treasury.to_account_info(),
+                    vault: ctx.accounts.vault.to_account_info(),
+                    system_program: ctx.accounts.system_program.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+        )
+    }
+
+    pub fn init_ledger(ctx: Context<InitLedger>) -> Result<()> {
+        ctx.accounts.ledger.bump = ctx.bumps.ledger;
+        Ok(())
+    }
+
+    pub fn deposit_from_ledger(ctx: Context<DepositFromLedger>, amount: u64) -> Result<()> {
+        let ledger = ctx.accounts.ledger.to_account_info();
+        let rent = Rent::get()?.minimum_balance(ledger.data_len());
+        require_gte!(ledger.lamports().saturating_sub(rent), amount);
+
+        // The ledger is owned by this program, so its lamports can be moved directly
+        **ledger.try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.deposit_buffer.try_borrow_mut_lamports()? += amount;
+
+        let signer_seeds: &[&[u8]] = &[AUTHORITY_SEED, &[ctx.bumps.authority]];
+
+        on_chain_vault::cpi::deposit_from_program(CpiContext::new_with_signer(
+            ctx.accounts.vault_program.to_account_info(),
+            DepositFromProgram {
+                depositor: ctx.accounts.authority.to_account_info(),
+                vault: ctx.accounts.vault.to_account_info(),
+                deposit_buffer: ctx.accounts.deposit_buffer.to_account_info(),
+                system_program: ctx.accounts.system_program.to_account_info(),
+            },
+            &[signer_seeds],
+        ))
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Ledger {
+    pub bump: u8,
+}
+
+#[derive(Accounts)]
+pub struct DepositFromTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [TREASURY_SEED],
+        bump,
+    )]
+    pub treasury: SystemAccount<'info>,
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    pub vault_program: Program<'info, OnChainVault>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitLedger<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Ledger::INIT_SPACE,
+        seeds = [LEDGER_SEED],
+        bump
+    )]
+    pub ledger: Account<'info, Ledger>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct DepositFromLedger<'info> {
+    #[account(
+        mut,
+        seeds = [LEDGER_SEED],
+        bump = ledger.bump,
+    )]
+    pub ledger: Account<'info, Ledger>,
+    /// CHECK: PDA signer only, holds no data
+    #[account(
+        seeds = [AUTHORITY_SEED],
+        bump,
+    )]
+    pub authority: UncheckedAccount<'info>,
+    #[account(mut)]
+    pub vault: Account<'info, Vault>,
+    /// CHECK: seeds are checked by the vault program
+    #[account(mut)]
+    pub deposit_buffer: UncheckedAccount<'info>,
+    pub vault_program: Program<'info, OnChainVault>,
+    pub system_program: Program<'info, System>,
+}
@@ -2,6 +2,7 @@ import * as anchor from "@coral-xyz/anchor";
 import { Program } from "@coral-xyz/anchor";
 import { OnChainVault } from "../target/types/on_chain_vault";
 import { assert } from "chai";
+import fs from "fs";
 
 describe("on-chain-vault", async () => {
   const provider = anchor.AnchorProvider.local();
@@ -670,8 +671,1373 @@ describe("on-chain-vault", async () => {
       vault: vaultAlicePDA,
     }).signers([alice]).rpc({ commitment: "confirmed" });
   });
+  describe("Delegated allowances", () => {
+    const dave = anchor.web3.Keypair.generate();
+    const erin = anchor.web3.Keypair.generate();
+
+    const getDelegatePDA = (vault: anchor.web3.PublicKey, delegate: anchor.web3.PublicKey) => {
+      return anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("delegate"), vault.toBuffer(), delegate.toBuffer()],
+        program.programId
+      );
+    };
+
+    const [daveDelegatePDA] = getDelegatePDA(vaultAlicePDA, dave.publicKey);
+    const [erinDelegatePDA] = getDelegatePDA(vaultAlicePDA, erin.publicKey);
+
+    it("Alice approves Dave as a delegate", async () => {
+      await airdrop(provider.connection, dave.publicKey);
+      await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)).accounts({
+        user: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      const allowance = new anchor.BN(500000);
+      const expiry = new anchor.BN(Math.floor(Date.now() / 1000) + 3600);
+
+      let txSig = await program.methods.approveDelegate(dave.publicKey, allowance, expiry).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+        delegateAccount: daveDelegatePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      const delegateData = await program.account.delegate.fetch(daveDelegatePDA);
+      assert.strictEqual(delegateData.vault.toString(), vaultAlicePDA.toString(), "Delegate vault should be Alice's vault");
+      assert.strictEqual(delegateData.delegate.toString(), dave.publicKey.toString(), "Delegate should be Dave");
+      assert.strictEqual(delegateData.allowance.toString(), allowance.toString(), "Delegate allowance should match");
+      assert.strictEqual(delegateData.expiry.toString(), expiry.toString(), "Delegate expiry should match");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "approveDelegateEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.delegate.toString(), dave.publicKey.toString(), "Event delegate should be Dave");
+        }
+      }
+      assert.isTrue(logsEmitted, "ApproveDelegateEvent should have been emitted");
+    });
+
+    it("Dave withdraws within his allowance", async () => {
+      const withdrawAmount = 200000;
+      const daveBalanceBefore = await provider.connection.getBalance(dave.publicKey);
+
+      let txSig = await program.methods.delegatedWithdraw(new anchor.BN(withdrawAmount)).accounts({
+        delegate: dave.publicKey,
+        vault: vaultAlicePDA,
+        delegateAccount: daveDelegatePDA,
+      }).signers([dave]).rpc({ commitment: "confirmed" });
+
+      const daveBalanceAfter = await provider.connection.getBalance(dave.publicKey);
+      assert.isTrue(daveBalanceAfter > daveBalanceBefore, "Dave's balance should increase after delegated withdrawal");
+
+      const delegateData = await program.account.delegate.fetch(daveDelegatePDA);
+      assert.strictEqual(delegateData.allowance.toString(), "300000", "Allowance should be reduced by the withdrawn amount");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "delegatedWithdrawEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.amount.toString(), withdrawAmount.toString(), "Event amount should match withdrawal amount");
+          assert.strictEqual(event.data.delegate.toString(), dave.publicKey.toString(), "Event delegate should be Dave");
+          assert.strictEqual(event.data.vault.toString(), vaultAlicePDA.toString(), "Event vault should be Alice's vault");
+          assert.strictEqual(event.data.remainingAllowance.toString(), "300000", "Event should carry the remaining allowance");
+        }
+      }
+      assert.isTrue(logsEmitted, "DelegatedWithdrawEvent should have been emitted");
+    });
+
+    it("Dave cannot withdraw more than his remaining allowance", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.delegatedWithdraw(new anchor.BN(300001)).accounts({
+          delegate: dave.publicKey,
+          vault: vaultAlicePDA,
+          delegateAccount: daveDelegatePDA,
+        }).signers([dave]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AllowanceExceeded", "Should fail with AllowanceExceeded error");
+      }
+      assert.strictEqual(flag, "Failed", "Withdrawing above the allowance should fail");
+    });
+
+    it("Expired delegate cannot withdraw", async () => {
+      await airdrop(provider.connection, erin.publicKey);
+      const expiry = new anchor.BN(Math.floor(Date.now() / 1000) - 60);
+
+      await program.methods.approveDelegate(erin.publicKey, new anchor.BN(500000), expiry).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+        delegateAccount: erinDelegatePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      let flag = "This should fail";
+      try {
+        await program.methods.delegatedWithdraw(new anchor.BN(1000)).accounts({
+          delegate: erin.publicKey,
+          vault: vaultAlicePDA,
+          delegateAccount: erinDelegatePDA,
+        }).signers([erin]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "DelegateExpired", "Should fail with DelegateExpired error");
+      }
+      assert.strictEqual(flag, "Failed", "Expired delegate should not be able to withdraw");
+    });
+
+    it("Alice revokes Dave and he can no longer withdraw", async () => {
+      await program.methods.revokeDelegate().accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+        delegateAccount: daveDelegatePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      const delegateInfo = await provider.connection.getAccountInfo(daveDelegatePDA);
+      assert.isNull(delegateInfo, "Delegate account should be closed after revoke");
+
+      let flag = "This should fail";
+      try {
+        await program.methods.delegatedWithdraw(new anchor.BN(1000)).accounts({
+          delegate: dave.publicKey,
+          vault: vaultAlicePDA,
+          delegateAccount: daveDelegatePDA,
+        }).signers([dave]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+      }
+      assert.strictEqual(flag, "Failed", "Revoked delegate should not be able to withdraw");
+    });
+  });
+
+  describe("Native staking", () => {
+    const STAKE_PROGRAM_ID = anchor.web3.StakeProgram.programId;
+    const [stakeBobPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("stake"), vaultBobPDA.toBuffer()],
+      program.programId
+    );
+    const stakeAmount = 2 * anchor.web3.LAMPORTS_PER_SOL;
+    let voteAccount: anchor.web3.PublicKey;
+
+    const stakeAccounts = () => ({
+      vaultAuthority: bob.publicKey,
+      vault: vaultBobPDA,
+      stakeAccount: stakeBobPDA,
+      clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+      stakeHistory: anchor.web3.SYSVAR_STAKE_HISTORY_PUBKEY,
+      stakeProgram: STAKE_PROGRAM_ID,
+    });
+
+    before(async () => {
+      const voteAccounts = await provider.connection.getVoteAccounts();
+      voteAccount = new anchor.web3.PublicKey(voteAccounts.current[0].votePubkey);
+
+      await program.methods.deposit(new anchor.BN(3 * anchor.web3.LAMPORTS_PER_SOL)).accounts({
+        user: bob.publicKey,
+        vault: vaultBobPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Cannot stake from a locked vault", async () => {
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultBobPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+
+      let flag = "This should fail";
+      try {
+        await program.methods.delegateStake(new anchor.BN(stakeAmount)).accounts({
+          ...stakeAccounts(),
+          voteAccount,
+          rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+          stakeConfig: anchor.web3.STAKE_CONFIG_ID,
+        }).signers([bob]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "VaultLocked", "Should fail with VaultLocked error");
+      }
+      assert.strictEqual(flag, "Failed", "Staking from a locked vault should fail");
+
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultBobPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Bob delegates part of his vault to a validator", async () => {
+      const vaultBalanceBefore = await provider.connection.getBalance(vaultBobPDA);
+
+      let txSig = await program.methods.delegateStake(new anchor.BN(stakeAmount)).accounts({
+        ...stakeAccounts(),
+        voteAccount,
+        rent: anchor.web3.SYSVAR_RENT_PUBKEY,
+        stakeConfig: anchor.web3.STAKE_CONFIG_ID,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+
+      const vaultBalanceAfter = await provider.connection.getBalance(vaultBobPDA);
+      assert.strictEqual(vaultBalanceBefore - vaultBalanceAfter, stakeAmount, "Vault balance should decrease by the staked amount");
+
+      const stakeInfo = await provider.connection.getAccountInfo(stakeBobPDA);
+      assert.strictEqual(stakeInfo.owner.toString(), STAKE_PROGRAM_ID.toString(), "Stake account should be owned by the stake program");
+      assert.isTrue(stakeInfo.lamports > stakeAmount, "Stake account should hold the staked amount plus rent");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "delegateStakeEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.amount.toString(), stakeAmount.toString(), "Event amount should match staked amount");
+          assert.strictEqual(event.data.voteAccount.toString(), voteAccount.toString(), "Event vote account should match");
+        }
+      }
+      assert.isTrue(logsEmitted, "DelegateStakeEvent should have been emitted");
+    });
+
+    it("Bob deactivates and withdraws the stake back into his vault", async () => {
+      await program.methods.deactivateStake().accounts({
+        vaultAuthority: bob.publicKey,
+        vault: vaultBobPDA,
+        stakeAccount: stakeBobPDA,
+        clock: anchor.web3.SYSVAR_CLOCK_PUBKEY,
+        stakeProgram: STAKE_PROGRAM_ID,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+
+      // Stake deactivated in the epoch it was delegated is withdrawable right away
+      const stakeBalance = await provider.connection.getBalance(stakeBobPDA);
+      const vaultBalanceBefore = await provider.connection.getBalance(vaultBobPDA);
+
+      await program.methods.withdrawStake(new anchor.BN(stakeBalance)).accounts(stakeAccounts())
+        .signers([bob]).rpc({ commitment: "confirmed" });
+
+      const vaultBalanceAfter = await provider.connection.getBalance(vaultBobPDA);
+      assert.strictEqual(vaultBalanceAfter - vaultBalanceBefore, stakeBalance, "Vault should receive the whole stake balance");
+      assert.isNull(await provider.connection.getAccountInfo(stakeBobPDA), "Emptied stake account should be closed");
+    });
+  });
+
+  describe("Dead-man switch", () => {
+    const frank = anchor.web3.Keypair.generate();
+    const grace = anchor.web3.Keypair.generate();
+    const [vaultFrankPDA] = getVaultPDA(frank.publicKey);
+
+    const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+    before(async () => {
+      await airdrop(provider.connection, frank.publicKey);
+      await airdrop(provider.connection, grace.publicKey);
+
+      await program.methods.initVault(false).accounts({
+        vaultAuthority: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+
+      await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)).accounts({
+        user: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Frank sets Grace as beneficiary", async () => {
+      let txSig = await program.methods.setBeneficiary(grace.publicKey, new anchor.BN(3600)).accounts({
+        vaultAuthority: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+
+      const vaultData = await program.account.vault.fetch(vaultFrankPDA);
+      assert.strictEqual(vaultData.beneficiary.toString(), grace.publicKey.toString(), "Beneficiary should be Grace");
+      assert.strictEqual(vaultData.inactivityPeriod.toString(), "3600", "Inactivity period should be stored");
+      assert.isTrue(vaultData.lastHeartbeat.toNumber() > 0, "Setting a beneficiary should record a heartbeat");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "setBeneficiaryEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.beneficiary.toString(), grace.publicKey.toString(), "Event beneficiary should be Grace");
+        }
+      }
+      assert.isTrue(logsEmitted, "SetBeneficiaryEvent should have been emitted");
+    });
+
+    it("Heartbeat updates last_heartbeat", async () => {
+      const before = (await program.account.vault.fetch(vaultFrankPDA)).lastHeartbeat.toNumber();
+      await sleep(1500);
+
+      await program.methods.heartbeat().accounts({
+        vaultAuthority: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+
+      const after = (await program.account.vault.fetch(vaultFrankPDA)).lastHeartbeat.toNumber();
+      assert.isTrue(after > before, "Heartbeat should move last_heartbeat forward");
+    });
+
+    it("Grace cannot claim while Frank is active", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.claimInheritance({ balance: {} }).accounts({
+          beneficiary: grace.publicKey,
+          vault: vaultFrankPDA,
+        }).signers([grace]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AuthorityStillActive", "Should fail with AuthorityStillActive error");
+      }
+      assert.strictEqual(flag, "Failed", "Claiming before the inactivity period should fail");
+    });
+
+    it("Only the beneficiary can claim", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.claimInheritance({ authority: {} }).accounts({
+          beneficiary: bob.publicKey,
+          vault: vaultFrankPDA,
+        }).signers([bob]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotBeneficiary", "Should fail with NotBeneficiary error");
+      }
+      assert.strictEqual(flag, "Failed", "Non-beneficiary should not be able to claim");
+    });
+
+    it("Grace claims the balance after Frank goes inactive", async () => {
+      await program.methods.setBeneficiary(grace.publicKey, new anchor.BN(1)).accounts({
+        vaultAuthority: frank.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([frank]).rpc({ commitment: "confirmed" });
+      await sleep(3000);
+
+      const graceBalanceBefore = await provider.connection.getBalance(grace.publicKey);
+
+      let txSig = await program.methods.claimInheritance({ balance: {} }).accounts({
+        beneficiary: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+
+      const graceBalanceAfter = await provider.connection.getBalance(grace.publicKey);
+      assert.isTrue(graceBalanceAfter > graceBalanceBefore, "Grace should receive the vault balance");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "inheritanceBalanceClaimedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.beneficiary.toString(), grace.publicKey.toString(), "Event beneficiary should be Grace");
+          assert.isTrue(event.data.amount.toNumber() > 0, "Event amount should be positive");
+        }
+      }
+      assert.isTrue(logsEmitted, "InheritanceBalanceClaimedEvent should have been emitted");
+    });
+
+    it("Grace becomes the new authority and can withdraw", async () => {
+      let txSig = await program.methods.claimInheritance({ authority: {} }).accounts({
+        beneficiary: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+
+      const vaultData = await program.account.vault.fetch(vaultFrankPDA);
+      assert.strictEqual(vaultData.vaultAuthority.toString(), grace.publicKey.toString(), "Grace should be the new vault authority");
+      assert.strictEqual(vaultData.creator.toString(), frank.publicKey.toString(), "Vault creator should stay Frank");
+      assert.isNull(vaultData.beneficiary, "Beneficiary should be cleared");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "inheritanceAuthorityClaimedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.previousAuthority.toString(), frank.publicKey.toString(), "Event previous authority should be Frank");
+          assert.strictEqual(event.data.newAuthority.toString(), grace.publicKey.toString(), "Event new authority should be Grace");
+        }
+      }
+      assert.isTrue(logsEmitted, "InheritanceAuthorityClaimedEvent should have been emitted");
+
+      await program.methods.deposit(new anchor.BN(100000)).accounts({
+        user: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Grace clears the beneficiary without an inactivity period", async () => {
+      await program.methods.setBeneficiary(bob.publicKey, new anchor.BN(3600)).accounts({
+        vaultAuthority: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+
+      await program.methods.setBeneficiary(null, new anchor.BN(0)).accounts({
+        vaultAuthority: grace.publicKey,
+        vault: vaultFrankPDA,
+      }).signers([grace]).rpc({ commitment: "confirmed" });
+
+      const vaultData = await program.account.vault.fetch(vaultFrankPDA);
+      assert.isNull(vaultData.beneficiary, "Beneficiary should be cleared");
+      assert.strictEqual(vaultData.inactivityPeriod.toString(), "0", "Inactivity period should be reset");
+    });
+  });
+
+  describe("Escrow", () => {
+    const escrowAmount = 1_000_000;
+
+    const getEscrowPDA = (depositor: anchor.web3.PublicKey, seed: number) => {
+      return anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("escrow"), depositor.toBuffer(), new anchor.BN(seed).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    };
+
+    const fundEscrow = async (seed: number, expiry: number) => {
+      const [escrowPDA] = getEscrowPDA(alice.publicKey, seed);
+      await program.methods.fundEscrow(
+        new anchor.BN(seed), bob.publicKey, anatoly.publicKey, new anchor.BN(escrowAmount), new anchor.BN(expiry)
+      ).accounts({
+        depositor: alice.publicKey,
+        escrow: escrowPDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+      return escrowPDA;
+    };
+
+    const inOneHour = () => Math.floor(Date.now() / 1000) + 3600;
+
+    it("Alice funds an escrow for Bob with Anatoly as arbiter", async () => {
+      const escrowPDA = await fundEscrow(1, inOneHour());
+
+      const escrowData = await program.account.escrow.fetch(escrowPDA);
+      assert.strictEqual(escrowData.depositor.toString(), alice.publicKey.toString(), "Escrow depositor should be Alice");
+      assert.strictEqual(escrowData.payee.toString(), bob.publicKey.toString(), "Escrow payee should be Bob");
+      assert.strictEqual(escrowData.arbiter.toString(), anatoly.publicKey.toString(), "Escrow arbiter should be Anatoly");
+      assert.strictEqual(escrowData.amount.toString(), escrowAmount.toString(), "Escrow amount should match");
+    });
+
+    it("Payee cannot release the escrow", async () => {
+      const [escrowPDA] = getEscrowPDA(alice.publicKey, 1);
+
+      let flag = "This should fail";
+      try {
+        await program.methods.releaseEscrow().accounts({
+          caller: bob.publicKey,
+          depositor: alice.publicKey,
+          payee: bob.publicKey,
+          escrow: escrowPDA,
+        }).signers([bob]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "UnauthorizedEscrowParty", "Should fail with UnauthorizedEscrowParty error");
+      }
+      assert.strictEqual(flag, "Failed", "Payee should not be able to release");
+    });
+
+    it("Arbiter releases the escrow to the payee", async () => {
+      const [escrowPDA] = getEscrowPDA(alice.publicKey, 1);
+      const bobBalanceBefore = await provider.connection.getBalance(bob.publicKey);
+
+      let txSig = await program.methods.releaseEscrow().accounts({
+        caller: anatoly.publicKey,
+        depositor: alice.publicKey,
+        payee: bob.publicKey,
+        escrow: escrowPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+      const bobBalanceAfter = await provider.connection.getBalance(bob.publicKey);
+      assert.strictEqual(bobBalanceAfter - bobBalanceBefore, escrowAmount, "Bob should receive the escrowed amount");
+      assert.isNull(await provider.connection.getAccountInfo(escrowPDA), "Escrow should be closed after release");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "escrowReleasedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.releasedBy.toString(), anatoly.publicKey.toString(), "Event should name the arbiter");
+        }
+      }
+      assert.isTrue(logsEmitted, "EscrowReleasedEvent should have been emitted");
+    });
+
+    it("Payee refunds the escrow to the depositor", async () => {
+      const escrowPDA = await fundEscrow(2, inOneHour());
+      const aliceBalanceBefore = await provider.connection.getBalance(alice.publicKey);
+
+      await program.methods.refundEscrow().accounts({
+        caller: bob.publicKey,
+        depositor: alice.publicKey,
+        escrow: escrowPDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+
+      const aliceBalanceAfter = await provider.connection.getBalance(alice.publicKey);
+      assert.isTrue(aliceBalanceAfter - aliceBalanceBefore > escrowAmount, "Alice should get the amount and the rent back");
+      assert.isNull(await provider.connection.getAccountInfo(escrowPDA), "Escrow should be closed after refund");
+    });
+
+    it("Timeout refund fails before expiry and succeeds after it", async () => {
+      const activePDA = await fundEscrow(3, inOneHour());
+
+      let flag = "This should fail";
+      try {
+        await program.methods.timeoutRefundEscrow().accounts({
+          caller: anatoly.publicKey,
+          depositor: alice.publicKey,
+          escrow: activePDA,
+        }).signers([anatoly]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "EscrowNotExpired", "Should fail with EscrowNotExpired error");
+      }
+      assert.strictEqual(flag, "Failed", "Timeout refund before expiry should fail");
+
+      const expiredPDA = await fundEscrow(4, Math.floor(Date.now() / 1000) - 60);
+      const stranger = anchor.web3.Keypair.generate();
+      await airdrop(provider.connection, stranger.publicKey);
+
+      await program.methods.timeoutRefundEscrow().accounts({
+        caller: stranger.publicKey,
+        depositor: alice.publicKey,
+        escrow: expiredPDA,
+      }).signers([stranger]).rpc({ commitment: "confirmed" });
+
+      assert.isNull(await provider.connection.getAccountInfo(expiredPDA), "Expired escrow should be closed");
+    });
+  });
+
+  describe("Payment streams", () => {
+    const henry = anchor.web3.Keypair.generate();
+    const rate = 1000;
+    const getStreamPDA = (streamId: number) => {
+      return anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("stream"), vaultAnatolyPDA.toBuffer(), henry.publicKey.toBuffer(), new anchor.BN(streamId).toArrayLike(Buffer, "le", 8)],
+        program.programId
+      );
+    };
+    const [streamPDA] = getStreamPDA(0);
+
+    before(async () => {
+      await airdrop(provider.connection, henry.publicKey);
+      await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)).accounts({
+        user: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Anatoly opens a stream to Henry", async () => {
+      const now = Math.floor(Date.now() / 1000);
+
+      await program.methods.createStream(
+        henry.publicKey, new anchor.BN(0), new anchor.BN(rate), new anchor.BN(now - 10), new anchor.BN(now + 3600)
+      ).accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+        stream: streamPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+      const streamData = await program.account.stream.fetch(streamPDA);
+      assert.strictEqual(streamData.vault.toString(), vaultAnatolyPDA.toString(), "Stream should draw from Anatoly's vault");
+      assert.strictEqual(streamData.recipient.toString(), henry.publicKey.toString(), "Stream recipient should be Henry");
+      assert.strictEqual(streamData.ratePerSecond.toString(), rate.toString(), "Stream rate should match");
+      assert.strictEqual(streamData.withdrawn.toString(), "0", "Nothing should be withdrawn yet");
+    });
+
+    it("Anatoly opens a second stream to Henry under another id", async () => {
+      const now = Math.floor(Date.now() / 1000);
+      const [secondStreamPDA] = getStreamPDA(1);
+
+      await program.methods.createStream(
+        henry.publicKey, new anchor.BN(1), new anchor.BN(rate), new anchor.BN(now), new anchor.BN(now + 60)
+      ).accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+        stream: secondStreamPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+      const streamData = await program.account.stream.fetch(secondStreamPDA);
+      assert.strictEqual(streamData.streamId.toString(), "1", "Second stream should keep its id");
+      assert.isNotNull(await provider.connection.getAccountInfo(streamPDA), "First stream should be untouched");
+    });
+
+    it("Henry withdraws what has accrued", async () => {
+      const henryBalanceBefore = await provider.connection.getBalance(henry.publicKey);
+
+      let txSig = await program.methods.withdrawFromStream().accounts({
+        recipient: henry.publicKey,
+        vault: vaultAnatolyPDA,
+        stream: streamPDA,
+      }).signers([henry]).rpc({ commitment: "confirmed" });
+
+      const henryBalanceAfter = await provider.connection.getBalance(henry.publicKey);
+      const streamData = await program.account.stream.fetch(streamPDA);
+      assert.isTrue(streamData.withdrawn.toNumber() >= 10 * rate, "At least ten seconds should have accrued");
+      assert.isTrue(henryBalanceAfter > henryBalanceBefore, "Henry's balance should increase");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "streamWithdrawEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.amount.toString(), streamData.withdrawn.toString(), "Event amount should match withdrawn amount");
+          assert.strictEqual(event.data.recipient.toString(), henry.publicKey.toString(), "Event recipient should be Henry");
+        }
+      }
+      assert.isTrue(logsEmitted, "StreamWithdrawEvent should have been emitted");
+    });
+
+    it("Henry cannot withdraw while the vault is locked", async () => {
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+      let flag = "This should fail";
+      try {
+        await program.methods.withdrawFromStream().accounts({
+          recipient: henry.publicKey,
+          vault: vaultAnatolyPDA,
+          stream: streamPDA,
+        }).signers([henry]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "VaultLocked", "Should fail with VaultLocked error");
+      }
+      assert.strictEqual(flag, "Failed", "Stream withdrawal from a locked vault should fail");
+
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Anatoly cancels the stream and the accrued part is settled", async () => {
+      const henryBalanceBefore = await provider.connection.getBalance(henry.publicKey);
+
+      let txSig = await program.methods.cancelStream().accounts({
+        vaultAuthority: anatoly.publicKey,
+        vault: vaultAnatolyPDA,
+        recipient: henry.publicKey,
+        stream: streamPDA,
+      }).signers([anatoly]).rpc({ commitment: "confirmed" });
+
+      const henryBalanceAfter = await provider.connection.getBalance(henry.publicKey);
+      assert.isTrue(henryBalanceAfter >= henryBalanceBefore, "Henry should receive any accrued lamports");
+      assert.isNull(await provider.connection.getAccountInfo(streamPDA), "Stream should be closed after cancel");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "streamCancelledEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.settled.toNumber(), henryBalanceAfter - henryBalanceBefore, "Event should report the settled amount");
+          assert.isTrue(event.data.returned.toNumber() > 0, "Unaccrued lamports should stay in the vault");
+        }
+      }
+      assert.isTrue(logsEmitted, "StreamCancelledEvent should have been emitted");
+    });
+  });
+
+  describe("Pull authorizations", () => {
+    const ivan = anchor.web3.Keypair.generate();
+    const amountPerPeriod = 50000;
+    const period = 3600;
+    const [pullPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("pull"), vaultAlicePDA.toBuffer(), ivan.publicKey.toBuffer()],
+      program.programId
+    );
+
+    const pull = () => program.methods.pull().accounts({
+      merchant: ivan.publicKey,
+      vault: vaultAlicePDA,
+      pullAuthorization: pullPDA,
+    }).signers([ivan]).rpc({ commitment: "confirmed" });
+
+    it("Alice authorizes Ivan to pull a subscription", async () => {
+      await airdrop(provider.connection, ivan.publicKey);
+
+      await program.methods.createPullAuthorization(ivan.publicKey, new anchor.BN(amountPerPeriod), new anchor.BN(period)).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+        pullAuthorization: pullPDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      const pullData = await program.account.pullAuthorization.fetch(pullPDA);
+      assert.strictEqual(pullData.merchant.toString(), ivan.publicKey.toString(), "Merchant should be Ivan");
+      assert.strictEqual(pullData.amountPerPeriod.toString(), amountPerPeriod.toString(), "Amount per period should match");
+      assert.strictEqual(pullData.period.toString(), period.toString(), "Period should match");
+    });
+
+    it("Ivan pulls once and cannot pull again in the same period", async () => {
+      const ivanBalanceBefore = await provider.connection.getBalance(ivan.publicKey);
+      const nextPullBefore = (await program.account.pullAuthorization.fetch(pullPDA)).nextPullAt.toNumber();
+
+      let txSig = await pull();
+
+      const ivanBalanceAfter = await provider.connection.getBalance(ivan.publicKey);
+      assert.isTrue(ivanBalanceAfter > ivanBalanceBefore, "Ivan's balance should increase after a pull");
+
+      const pullData = await program.account.pullAuthorization.fetch(pullPDA);
+      assert.strictEqual(pullData.nextPullAt.toNumber(), nextPullBefore + period, "Next pull should be one period later");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "pullEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.amount.toString(), amountPerPeriod.toString(), "Event amount should match");
+          assert.strictEqual(event.data.merchant.toString(), ivan.publicKey.toString(), "Event merchant should be Ivan");
+        }
+      }
+      assert.isTrue(logsEmitted, "PullEvent should have been emitted");
+
+      let flag = "This should fail";
+      try {
+        await pull();
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "PullTooEarly", "Should fail with PullTooEarly error");
+      }
+      assert.strictEqual(flag, "Failed", "Second pull in the same period should fail");
+    });
+
+    it("Alice revokes Ivan and he can no longer pull", async () => {
+      await program.methods.revokePullAuthorization().accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+        pullAuthorization: pullPDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      assert.isNull(await provider.connection.getAccountInfo(pullPDA), "Pull authorization should be closed");
+
+      let flag = "This should fail";
+      try {
+        await pull();
+      } catch (error) {
+        flag = "Failed";
+      }
+      assert.strictEqual(flag, "Failed", "Revoked merchant should not be able to pull");
+    });
+  });
+
+  describe("Deposit and withdraw pause flags", () => {
+    it("Paused withdrawals still accept deposits", async () => {
+      let txSig = await program.methods.setWithdrawPause(true).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      const vaultData = await program.account.vault.fetch(vaultAlicePDA);
+      assert.isTrue(vaultData.withdrawalsPaused, "Withdrawals should be paused");
+      assert.isFalse(vaultData.depositsPaused, "Deposits should not be paused");
+      assert.isFalse(vaultData.locked, "Vault should not count as locked");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "withdrawPauseEvent") {
+          logsEmitted = true;
+          assert.isTrue(event.data.paused, "Event should report withdrawals paused");
+        }
+      }
+      assert.isTrue(logsEmitted, "WithdrawPauseEvent should have been emitted");
+
+      await program.methods.deposit(new anchor.BN(100000)).accounts({
+        user: bob.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([bob]).rpc({ commitment: "confirmed" });
+
+      let flag = "This should fail";
+      try {
+        await program.methods.withdraw(new anchor.BN(100000)).accounts({
+          vaultAuthority: alice.publicKey,
+          vault: vaultAlicePDA,
+        }).signers([alice]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "WithdrawalsPaused", "Should fail with WithdrawalsPaused error");
+      }
+      assert.strictEqual(flag, "Failed", "Withdrawing while withdrawals are paused should fail");
+    });
+
+    it("Paused deposits still allow withdrawals", async () => {
+      await program.methods.setWithdrawPause(false).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+      await program.methods.setDepositPause(true).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      let flag = "This should fail";
+      try {
+        await program.methods.deposit(new anchor.BN(100000)).accounts({
+          user: bob.publicKey,
+          vault: vaultAlicePDA,
+        }).signers([bob]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "DepositsPaused", "Should fail with DepositsPaused error");
+      }
+      assert.strictEqual(flag, "Failed", "Depositing while deposits are paused should fail");
+
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Toggle lock pauses and resumes both flows together", async () => {
+      // Only deposits are paused, so the vault is not locked yet
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      let vaultData = await program.account.vault.fetch(vaultAlicePDA);
+      assert.isTrue(vaultData.locked, "Vault should be locked");
+      assert.isTrue(vaultData.depositsPaused && vaultData.withdrawalsPaused, "Both flows should be paused");
+
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: alice.publicKey,
+        vault: vaultAlicePDA,
+      }).signers([alice]).rpc({ commitment: "confirmed" });
+
+      vaultData = await program.account.vault.fetch(vaultAlicePDA);
+      assert.isFalse(vaultData.locked, "Vault should be unlocked");
+      assert.isFalse(vaultData.depositsPaused || vaultData.withdrawalsPaused, "Both flows should be resumed");
+    });
+  });
+
+  describe("Social recovery", () => {
+    const heidi = anchor.web3.Keypair.generate();
+    const ivan = anchor.web3.Keypair.generate();
+    const judy = anchor.web3.Keypair.generate();
+    const mallory = anchor.web3.Keypair.generate();
+    const heidiNewKey = anchor.web3.Keypair.generate();
+    const [vaultHeidiPDA] = getVaultPDA(heidi.publicKey);
+
+    const [guardianSetPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+      [Buffer.from("guardians"), vaultHeidiPDA.toBuffer()],
+      program.programId
+    );
+    const getRecoveryProposalPDA = (proposer: anchor.web3.PublicKey) => {
+      return anchor.web3.PublicKey.findProgramAddressSync(
+        [Buffer.from("recovery"), vaultHeidiPDA.toBuffer(), proposer.toBuffer()],
+        program.programId
+      );
+    };
+    const [recoveryProposalPDA] = getRecoveryProposalPDA(ivan.publicKey);
+
+    const sleep = (ms: number) => new Promise((resolve) => setTimeout(resolve, ms));
+
+    const proposeRecovery = (guardian: anchor.web3.Keypair) =>
+      program.methods.proposeRecovery(heidiNewKey.publicKey).accounts({
+        guardian: guardian.publicKey,
+        vault: vaultHeidiPDA,
+        guardianSet: guardianSetPDA,
+        recoveryProposal: getRecoveryProposalPDA(guardian.publicKey)[0],
+      }).signers([guardian]).rpc({ commitment: "confirmed" });
+
+    const approveRecovery = (guardian: anchor.web3.Keypair) =>
+      program.methods.approveRecovery().accounts({
+        guardian: guardian.publicKey,
+        guardianSet: guardianSetPDA,
+        recoveryProposal: recoveryProposalPDA,
+      }).signers([guardian]).rpc({ commitment: "confirmed" });
+
+    const executeRecovery = () =>
+      program.methods.executeRecovery().accounts({
+        executor: mallory.publicKey,
+        vault: vaultHeidiPDA,
+        guardianSet: guardianSetPDA,
+        proposer: ivan.publicKey,
+        recoveryProposal: recoveryProposalPDA,
+      }).signers([mallory]).rpc({ commitment: "confirmed" });
+
+    before(async () => {
+      await airdrop(provider.connection, heidi.publicKey);
+      await airdrop(provider.connection, ivan.publicKey);
+      await airdrop(provider.connection, judy.publicKey);
+      await airdrop(provider.connection, mallory.publicKey);
+      await airdrop(provider.connection, heidiNewKey.publicKey);
+
+      await program.methods.initVault(false).accounts({
+        vaultAuthority: heidi.publicKey,
+        vault: vaultHeidiPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+      await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)).accounts({
+        user: heidi.publicKey,
+        vault: vaultHeidiPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Heidi sets Ivan and Judy as guardians", async () => {
+      let txSig = await program.methods.setGuardians([ivan.publicKey, judy.publicKey], 2, new anchor.BN(2)).accounts({
+        vaultAuthority: heidi.publicKey,
+        vault: vaultHeidiPDA,
+        guardianSet: guardianSetPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+      const guardianSet = await program.account.guardianSet.fetch(guardianSetPDA);
+      assert.strictEqual(guardianSet.vault.toString(), vaultHeidiPDA.toString(), "Guardian set should belong to Heidi's vault");
+      assert.deepEqual(guardianSet.guardians.map((g) => g.toString()), [ivan.publicKey.toString(), judy.publicKey.toString()], "Guardians should be Ivan and Judy");
+      assert.strictEqual(guardianSet.threshold, 2, "Threshold should be stored");
+      assert.strictEqual(guardianSet.recoveryDelay.toString(), "2", "Recovery delay should be stored");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "setGuardiansEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.threshold, 2, "Event threshold should be 2");
+          assert.strictEqual(event.data.guardians.length, 2, "Event should list both guardians");
+        }
+      }
+      assert.isTrue(logsEmitted, "SetGuardiansEvent should have been emitted");
+    });
+
+    it("Cannot set a threshold above the guardian count", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.setGuardians([ivan.publicKey, judy.publicKey], 3, new anchor.BN(2)).accounts({
+          vaultAuthority: heidi.publicKey,
+          vault: vaultHeidiPDA,
+          guardianSet: guardianSetPDA,
+        }).signers([heidi]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidGuardianSet", "Should fail with InvalidGuardianSet error");
+      }
+      assert.strictEqual(flag, "Failed", "Threshold above the guardian count should fail");
+    });
+
+    it("Only a guardian can propose a recovery", async () => {
+      let flag = "This should fail";
+      try {
+        await proposeRecovery(mallory);
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotGuardian", "Should fail with NotGuardian error");
+      }
+      assert.strictEqual(flag, "Failed", "Non-guardian should not be able to propose a recovery");
+    });
+
+    it("Ivan proposes a recovery that is not executable yet", async () => {
+      let txSig = await proposeRecovery(ivan);
+
+      const proposal = await program.account.recoveryProposal.fetch(recoveryProposalPDA);
+      assert.strictEqual(proposal.newAuthority.toString(), heidiNewKey.publicKey.toString(), "Proposal should name Heidi's new key");
+      assert.strictEqual(proposal.approvals.length, 1, "Proposer should count as the first approval");
+      assert.strictEqual(proposal.executableAt.toNumber(), 0, "Waiting period should not start below the threshold");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "recoveryProposedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.proposer.toString(), ivan.publicKey.toString(), "Event proposer should be Ivan");
+          assert.strictEqual(event.data.newAuthority.toString(), heidiNewKey.publicKey.toString(), "Event new authority should be Heidi's new key");
+        }
+      }
+      assert.isTrue(logsEmitted, "RecoveryProposedEvent should have been emitted");
+
+      let flag = "This should fail";
+      try {
+        await approveRecovery(ivan);
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AlreadyApproved", "Should fail with AlreadyApproved error");
+      }
+      assert.strictEqual(flag, "Failed", "Approving twice should fail");
+
+      flag = "This should fail";
+      try {
+        await executeRecovery();
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "RecoveryNotReady", "Should fail with RecoveryNotReady error");
+      }
+      assert.strictEqual(flag, "Failed", "Executing below the threshold should fail");
+    });
+
+    it("Judy opens her own proposal next to Ivan's and cancels it", async () => {
+      const [judyProposalPDA] = getRecoveryProposalPDA(judy.publicKey);
+      await proposeRecovery(judy);
+
+      assert.isNotNull(await provider.connection.getAccountInfo(recoveryProposalPDA, "confirmed"), "Ivan's proposal should stay open");
+      const proposal = await program.account.recoveryProposal.fetch(judyProposalPDA);
+      assert.strictEqual(proposal.proposer.toString(), judy.publicKey.toString(), "Proposal should belong to Judy");
+      assert.strictEqual(proposal.previousAuthority.toString(), heidi.publicKey.toString(), "Proposal should record the authority it replaces");
+
+      let txSig = await program.methods.cancelRecovery().accounts({
+        proposer: judy.publicKey,
+        recoveryProposal: judyProposalPDA,
+      }).signers([judy]).rpc({ commitment: "confirmed" });
+
+      assert.isNull(await provider.connection.getAccountInfo(judyProposalPDA, "confirmed"), "Judy's proposal should be closed");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let logsEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "recoveryCancelledEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.proposer.toString(), judy.publicKey.toString(), "Event proposer should be Judy");
+        }
+      }
+      assert.isTrue(logsEmitted, "RecoveryCancelledEvent should have been emitted");
+    });
+
+    it("Only the proposer can cancel a proposal", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.cancelRecovery().accounts({
+          proposer: mallory.publicKey,
+          recoveryProposal: recoveryProposalPDA,
+        }).signers([mallory]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+      }
+      assert.strictEqual(flag, "Failed", "Someone other than the proposer should not be able to cancel");
+    });
+
+    it("Heidi vetoes the recovery", async () => {
+      let txSig = await program.methods.vetoRecovery().accounts({
+        vaultAuthority: heidi.publicKey,
+        vault: vaultHeidiPDA,
+        proposer: ivan.publicKey,
+        recoveryProposal: recoveryProposalPDA,
+      }).signers([heidi]).rpc({ commitment: "confirmed" });
+
+      const proposalInfo = await provider.connection.getAccountInfo(recoveryProposalPDA, "confirmed");
+      assert.isNull(proposalInfo, "Proposal should be closed");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      const events = eventParser.parseLogs(tx.meta.logMessages);
+
+      let logsEmitted = false;
+      for (let event of events) {
+        if (event.name === "recoveryVetoedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.vaultAuthority.toString(), heidi.publicKey.toString(), "Event authority should be Heidi");
+        }
+      }
+      assert.isTrue(logsEmitted, "RecoveryVetoedEvent should have been emitted");
+    });
+
+    it("Recovery executes after the threshold and waiting period", async () => {
+      await proposeRecovery(ivan);
+      let txSig = await approveRecovery(judy);
+
+      const proposal = await program.account.recoveryProposal.fetch(recoveryProposalPDA);
+      assert.isTrue(proposal.executableAt.toNumber() > 0, "Waiting period should start once the threshold is reached");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let logsEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "recoveryApprovedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.guardian.toString(), judy.publicKey.toString(), "Event guardian should be Judy");
+          assert.strictEqual(event.data.approvals, 2, "Event should count both approvals");
+        }
+      }
+      assert.isTrue(logsEmitted, "RecoveryApprovedEvent should have been emitted");
+
+      let flag = "This should fail";
+      try {
+        await executeRecovery();
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "RecoveryNotReady", "Should fail with RecoveryNotReady error");
+      }
+      assert.strictEqual(flag, "Failed", "Executing during the waiting period should fail");
+
+      await sleep(3000);
+      txSig = await executeRecovery();
+
+      const vaultData = await program.account.vault.fetch(vaultHeidiPDA);
+      assert.strictEqual(vaultData.vaultAuthority.toString(), heidiNewKey.publicKey.toString(), "Heidi's new key should be the vault authority");
+      assert.strictEqual(vaultData.creator.toString(), heidi.publicKey.toString(), "Vault creator should stay Heidi");
+
+      const executeTx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      logsEmitted = false;
+      for (let event of eventParser.parseLogs(executeTx.meta.logMessages)) {
+        if (event.name === "recoveryExecutedEvent") {
+          logsEmitted = true;
+          assert.strictEqual(event.data.previousAuthority.toString(), heidi.publicKey.toString(), "Event previous authority should be Heidi");
+          assert.strictEqual(event.data.newAuthority.toString(), heidiNewKey.publicKey.toString(), "Event new authority should be Heidi's new key");
+        }
+      }
+      assert.isTrue(logsEmitted, "RecoveryExecutedEvent should have been emitted");
+
+      await program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: heidiNewKey.publicKey,
+        vault: vaultHeidiPDA,
+      }).signers([heidiNewKey]).rpc({ commitment: "confirmed" });
+    });
+
+    it("Heidi clears the guardian set", async () => {
+      await program.methods.setGuardians([], 0, new anchor.BN(2)).accounts({
+        vaultAuthority: heidiNewKey.publicKey,
+        vault: vaultHeidiPDA,
+        guardianSet: guardianSetPDA,
+      }).signers([heidiNewKey]).rpc({ commitment: "confirmed" });
+
+      const guardianSet = await program.account.guardianSet.fetch(guardianSetPDA);
+      assert.strictEqual(guardianSet.guardians.length, 0, "Guardian set should be empty");
+      assert.strictEqual(guardianSet.threshold, 0, "Threshold should be zero");
+
+      let flag = "This should fail";
+      try {
+        await proposeRecovery(ivan);
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotGuardian", "Should fail with NotGuardian error");
+      }
+      assert.strictEqual(flag, "Failed", "Former guardians should not be able to propose");
+    });
+  });
+
+  describe("Stored vault bump", () => {
+    // Re-deriving a PDA bump costs 1500 CU for every candidate bump tried, starting at 255.
+    // With the bump stored in the vault the cost no longer depends on how low the bump is.
+    const PDA_ATTEMPT_CU = 1500;
+
+    const keypairWithVaultBump = (matches: (bump: number) => boolean) => {
+      while (true) {
+        const keypair = anchor.web3.Keypair.generate();
+        const [, bump] = getVaultPDA(keypair.publicKey);
+        if (matches(bump)) {
+          return { keypair, bump };
+        }
+      }
+    };
+
+    const highBump = keypairWithVaultBump((bump) => bump === 255);
+    const lowBump = keypairWithVaultBump((bump) => bump <= 252);
+
+    const computeUnits = async (txSig: string) => {
+      const tx = await provider.connection.getTransaction(txSig, { commitment: "confirmed", maxSupportedTransactionVersion: 0 });
+      return tx.meta.computeUnitsConsumed;
+    };
+
+    before(async () => {
+      for (const { keypair } of [highBump, lowBump]) {
+        await airdrop(provider.connection, keypair.publicKey);
+
+        await program.methods.initVault(false).accounts({
+          vaultAuthority: keypair.publicKey,
+          vault: getVaultPDA(keypair.publicKey)[0],
+        }).signers([keypair]).rpc({ commitment: "confirmed" });
+
+        await program.methods.deposit(new anchor.BN(anchor.web3.LAMPORTS_PER_SOL)).accounts({
+          user: keypair.publicKey,
+          vault: getVaultPDA(keypair.publicKey)[0],
+        }).signers([keypair]).rpc({ commitment: "confirmed" });
+      }
+    });
+
+    it("Init vault stores the canonical bump", async () => {
+      for (const { keypair, bump } of [highBump, lowBump]) {
+        const vaultData = await program.account.vault.fetch(getVaultPDA(keypair.publicKey)[0]);
+        assert.strictEqual(vaultData.bump, bump, "Vault should store its canonical bump");
+      }
+    });
+
+    it("Withdraw cost does not depend on the bump", async () => {
+      const withdraw = ({ keypair }) => program.methods.withdraw(new anchor.BN(100000)).accounts({
+        vaultAuthority: keypair.publicKey,
+        vault: getVaultPDA(keypair.publicKey)[0],
+      }).signers([keypair]).rpc({ commitment: "confirmed" });
+
+      const highBumpUnits = await computeUnits(await withdraw(highBump));
+      const lowBumpUnits = await computeUnits(await withdraw(lowBump));
+
+      console.log(`      withdraw: ${highBumpUnits} CU (bump 255), ${lowBumpUnits} CU (bump ${lowBump.bump}), ` +
+        `re-deriving would add ${(255 - lowBump.bump) * PDA_ATTEMPT_CU} CU`);
+      assert.isBelow(Math.abs(lowBumpUnits - highBumpUnits), PDA_ATTEMPT_CU, "Withdraw should not search for the vault bump");
+    });
+
+    it("Toggle lock cost does not depend on the bump", async () => {
+      const toggleLock = ({ keypair }) => program.methods.toggleLock().accounts({
+        vaultAuthority: keypair.publicKey,
+        vault: getVaultPDA(keypair.publicKey)[0],
+      }).signers([keypair]).rpc({ commitment: "confirmed" });
+
+      const highBumpUnits = await computeUnits(await toggleLock(highBump));
+      const lowBumpUnits = await computeUnits(await toggleLock(lowBump));
+
+      console.log(`      toggle_lock: ${highBumpUnits} CU (bump 255), ${lowBumpUnits} CU (bump ${lowBump.bump}), ` +
+        `re-deriving would add ${(255 - lowBump.bump) * PDA_ATTEMPT_CU} CU`);
+      assert.isBelow(Math.abs(lowBumpUnits - highBumpUnits), PDA_ATTEMPT_CU, "Toggle lock should not search for the vault bump");
+    });
+  });
+
+
+  describe("Legacy vault migration", () => {
+    // Accounts preloaded from tests/fixtures, as written by the first version of the program
+    const legacyAuthority = loadKeypair("tests/fixtures/legacy-vault-authority.json");
+    const [legacyVaultPDA, legacyVaultBump] = getVaultPDA(legacyAuthority.publicKey);
+
+    before(async () => {
+      await airdrop(provider.connection, legacyAuthority.publicKey);
+    });
+
+    it("Cannot withdraw from a legacy vault before migrating it", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.withdraw(new anchor.BN(100000)).accounts({
+          vaultAuthority: legacyAuthority.publicKey,
+          vault: legacyVaultPDA,
+        }).signers([legacyAuthority]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AccountDidNotDeserialize", "Should fail with AccountDidNotDeserialize error");
+      }
+      assert.strictEqual(flag, "Failed", "Withdrawing from a legacy vault should fail");
+    });
+
+    it("Cannot migrate someone else's legacy vault", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.migrateVault().accounts({
+          vaultAuthority: alice.publicKey,
+          vault: legacyVaultPDA,
+        }).signers([alice]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Should fail with ConstraintHasOne error");
+      }
+      assert.strictEqual(flag, "Failed", "Migrating someone else's vault should fail");
+    });
+
+    it("Migrates a legacy vault", async () => {
+      const balanceBefore = await provider.connection.getBalance(legacyVaultPDA, "confirmed");
+
+      await program.methods.migrateVault().accounts({
+        vaultAuthority: legacyAuthority.publicKey,
+        vault: legacyVaultPDA,
+      }).signers([legacyAuthority]).rpc({ commitment: "confirmed" });
+
+      const accountInfo = await provider.connection.getAccountInfo(legacyVaultPDA, "confirmed");
+      assert.strictEqual(accountInfo.data.length, program.account.vault.size, "Vault should have the current layout");
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(accountInfo.data.length);
+      const rentBefore = await provider.connection.getMinimumBalanceForRentExemption(41);
+      assert.strictEqual(accountInfo.lamports - balanceBefore, rent - rentBefore, "Authority should pay for the extra rent");
+
+      const vaultData = await program.account.vault.fetch(legacyVaultPDA);
+      assert.isTrue(vaultData.vaultAuthority.equals(legacyAuthority.publicKey), "Vault authority should be kept");
+      assert.isTrue(vaultData.creator.equals(legacyAuthority.publicKey), "Creator should be the vault authority");
+      assert.strictEqual(vaultData.bump, legacyVaultBump, "Vault should store its canonical bump");
+      assert.isFalse(vaultData.locked, "Vault should stay unlocked");
+      assert.isFalse(vaultData.depositsPaused, "Deposits should not be paused");
+      assert.isFalse(vaultData.withdrawalsPaused, "Withdrawals should not be paused");
+      assert.isNull(vaultData.beneficiary, "Vault should have no beneficiary");
+    });
+
+    it("Cannot migrate a vault twice", async () => {
+      let flag = "This should fail";
+      try {
+        await program.methods.migrateVault().accounts({
+          vaultAuthority: legacyAuthority.publicKey,
+          vault: legacyVaultPDA,
+        }).signers([legacyAuthority]).rpc({ commitment: "confirmed" });
+      } catch (error) {
+        flag = "Failed";
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "VaultUpToDate", "Should fail with VaultUpToDate error");
+      }
+      assert.strictEqual(flag, "Failed", "Migrating a vault twice should fail");
+    });
+
+    it("Migrated vault works with instructions that use its stored bump", async () => {
+      const withdrawAmount = anchor.web3.LAMPORTS_PER_SOL;
+      const vaultBalanceBefore = await provider.connection.getBalance(legacyVaultPDA, "confirmed");
+
+      await program.methods.withdraw(new anchor.BN(withdrawAmount)).accounts({
+        vaultAuthority: legacyAuthority.publicKey,
+        vault: legacyVaultPDA,
+      }).signers([legacyAuthority]).rpc({ commitment: "confirmed" });
+
+      const vaultBalanceAfter = await provider.connection.getBalance(legacyVaultPDA, "confirmed");
+      assert.strictEqual(vaultBalanceBefore - vaultBalanceAfter, withdrawAmount, "Vault balance should decrease by the withdrawn amount");
+
+      await program.methods.toggleLock().accounts({
+        vaultAuthority: legacyAuthority.publicKey,
+        vault: legacyVaultPDA,
+      }).signers([legacyAuthority]).rpc({ commitment: "confirmed" });
+
+      const vaultData = await program.account.vault.fetch(legacyVaultPDA);
+      assert.isTrue(vaultData.locked, "Migrated vault should be locked");
+      assert.isTrue(vaultData.depositsPaused, "Deposits should be paused");
+      assert.isTrue(vaultData.withdrawalsPaused, "Withdrawals should be paused");
+    });
+  });
 });
 
 async function airdrop(connection: any, address: any, amount = 100 * anchor.web3.LAMPORTS_PER_SOL) {
   await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
 }
+
+function loadKeypair(path: string) {
+  return anchor.web3.Keypair.fromSecretKey(Uint8Array.from(JSON.parse(fs.readFileSync(path, "utf-8"))));
+}
@@ -0,0 +1,171 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { OnChainVault } from "../target/types/on_chain_vault";
+import { VaultDepositor } from "../target/types/vault_depositor";
+import { assert } from "chai";
+
+describe("vault-depositor", async () => {
+  const provider = anchor.AnchorProvider.local();
+  anchor.setProvider(provider);
+
+  const vaultProgram = anchor.workspace.OnChainVault as Program<OnChainVault>;
+  const program = anchor.workspace.VaultDepositor as Program<VaultDepositor>;
+
+  const owner = anchor.web3.Keypair.generate();
+
+  const [vaultPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("vault"), owner.publicKey.toBuffer()],
+    vaultProgram.programId
+  );
+  const [treasuryPDA] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("treasury")], program.programId);
+  const [ledgerPDA] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("ledger")], program.programId);
+  const [authorityPDA] = anchor.web3.PublicKey.findProgramAddressSync([Buffer.from("authority")], program.programId);
+  const [depositBufferPDA] = anchor.web3.PublicKey.findProgramAddressSync(
+    [Buffer.from("deposit"), vaultPDA.toBuffer(), authorityPDA.toBuffer()],
+    vaultProgram.programId
+  );
+
+  before(async () => {
+    await airdrop(provider.connection, owner.publicKey);
+
+    await vaultProgram.methods.initVault(false).accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    await program.methods.initLedger().accounts({
+      payer: owner.publicKey,
+      ledger: ledgerPDA,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    const tx = new anchor.web3.Transaction().add(
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: owner.publicKey,
+        toPubkey: treasuryPDA,
+        lamports: 5 * anchor.web3.LAMPORTS_PER_SOL,
+      }),
+      anchor.web3.SystemProgram.transfer({
+        fromPubkey: owner.publicKey,
+        toPubkey: ledgerPDA,
+        lamports: 5 * anchor.web3.LAMPORTS_PER_SOL,
+      }),
+    );
+    await anchor.web3.sendAndConfirmTransaction(provider.connection, tx, [owner], { commitment: "confirmed" });
+  });
+
+  it("Deposit from a system-owned PDA", async () => {
+    const amount = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+    const vaultBalanceBefore = await provider.connection.getBalance(vaultPDA, "confirmed");
+
+    let txSig = await program.methods.depositFromTreasury(amount).accounts({
+      treasury: treasuryPDA,
+      vault: vaultPDA,
+      vaultProgram: vaultProgram.programId,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).rpc({ commitment: "confirmed" });
+
+    const vaultBalanceAfter = await provider.connection.getBalance(vaultPDA, "confirmed");
+    assert.strictEqual(vaultBalanceAfter - vaultBalanceBefore, amount.toNumber(), "Vault balance should increase by the deposited amount");
+
+    // Check event was emitted
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(vaultProgram.programId, new anchor.BorshCoder(vaultProgram.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "depositEvent") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.user.toString(), treasuryPDA.toString(), "Event user should be the treasury PDA");
+        assert.strictEqual(event.data.vault.toString(), vaultPDA.toString(), "Event vault should match the vault PDA");
+        assert.strictEqual(event.data.amount.toString(), amount.toString(), "Event amount should match the deposited amount");
+      }
+    }
+    assert.isTrue(logsEmitted, "DepositEvent should have been emitted");
+  });
+
+  it("Deposit from a program-owned account", async () => {
+    const amount = new anchor.BN(anchor.web3.LAMPORTS_PER_SOL);
+    const vaultBalanceBefore = await provider.connection.getBalance(vaultPDA, "confirmed");
+
+    let txSig = await program.methods.depositFromLedger(amount).accounts({
+      ledger: ledgerPDA,
+      authority: authorityPDA,
+      vault: vaultPDA,
+      depositBuffer: depositBufferPDA,
+      vaultProgram: vaultProgram.programId,
+      systemProgram: anchor.web3.SystemProgram.programId,
+    }).rpc({ commitment: "confirmed" });
+
+    const vaultBalanceAfter = await provider.connection.getBalance(vaultPDA, "confirmed");
+    assert.strictEqual(vaultBalanceAfter - vaultBalanceBefore, amount.toNumber(), "Vault balance should increase by the deposited amount");
+
+    const bufferBalance = await provider.connection.getBalance(depositBufferPDA, "confirmed");
+    assert.strictEqual(bufferBalance, 0, "Deposit buffer should be swept empty");
+
+    // Check event was emitted
+    const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+    const eventParser = new anchor.EventParser(vaultProgram.programId, new anchor.BorshCoder(vaultProgram.idl));
+    const events = eventParser.parseLogs(tx.meta.logMessages);
+
+    let logsEmitted = false;
+    for (let event of events) {
+      if (event.name === "depositEvent") {
+        logsEmitted = true;
+        assert.strictEqual(event.data.user.toString(), authorityPDA.toString(), "Event user should be the depositor program's authority PDA");
+        assert.strictEqual(event.data.amount.toString(), amount.toString(), "Event amount should match the deposited amount");
+      }
+    }
+    assert.isTrue(logsEmitted, "DepositEvent should have been emitted");
+  });
+
+  it("Cannot deposit through CPI while deposits are paused", async () => {
+    await vaultProgram.methods.setDepositPause(true).accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultPDA,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+
+    let flag = "This should fail";
+    try {
+      await program.methods.depositFromTreasury(new anchor.BN(1000)).accounts({
+        treasury: treasuryPDA,
+        vault: vaultPDA,
+        vaultProgram: vaultProgram.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      }).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "DepositsPaused", "Expected DepositsPaused error");
+    }
+    assert.strictEqual(flag, "Failed", "Depositing through CPI into a paused vault should fail");
+
+    flag = "This should fail";
+    try {
+      await program.methods.depositFromLedger(new anchor.BN(1000)).accounts({
+        ledger: ledgerPDA,
+        authority: authorityPDA,
+        vault: vaultPDA,
+        depositBuffer: depositBufferPDA,
+        vaultProgram: vaultProgram.programId,
+        systemProgram: anchor.web3.SystemProgram.programId,
+      }).rpc({ commitment: "confirmed" });
+    } catch (error) {
+      flag = "Failed";
+      const err = anchor.AnchorError.parse(error.logs);
+      assert.strictEqual(err.error.errorCode.code, "DepositsPaused", "Expected DepositsPaused error");
+    }
+    assert.strictEqual(flag, "Failed", "Depositing through CPI into a paused vault should fail");
+
+    await vaultProgram.methods.setDepositPause(false).accounts({
+      vaultAuthority: owner.publicKey,
+      vault: vaultPDA,
+    }).signers([owner]).rpc({ commitment: "confirmed" });
+  });
+});
+
+async function airdrop(connection: any, address: any, amount = 100 * anchor.web3.LAMPORTS_PER_SOL) {
+  await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
+}
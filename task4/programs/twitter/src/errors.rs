@@ -16,4 +16,96 @@ pub enum TwitterError {
     MinDislikesReached,
     #[msg("Comment too Long")]
     CommentTooLong,
+    #[msg("Maximum number of Edits Reached")]
+    MaxEditsReached,
+    #[msg("Maximum number of Comments Reached")]
+    MaxCommentsReached,
+    #[msg("Minimum number of Comments Reached")]
+    MinCommentsReached,
+    #[msg("Cannot delete, tweet still has reactions")]
+    TweetHasReactions,
+    #[msg("Cannot delete, tweet still has comments")]
+    TweetHasComments,
+    #[msg("Cannot delete, every tweet version must be provided in order")]
+    InvalidTweetVersions,
+    #[msg("Cannot change, reaction already has this type")]
+    SameReaction,
+    #[msg("Maximum number of Reactions Reached")]
+    MaxReactionsReached,
+    #[msg("Minimum number of Reactions Reached")]
+    MinReactionsReached,
+    #[msg("Maximum number of Replies Reached")]
+    MaxRepliesReached,
+    #[msg("Minimum number of Replies Reached")]
+    MinRepliesReached,
+    #[msg("Cannot remove, comment still has replies")]
+    CommentHasReplies,
+    #[msg("Parent comment doesn't match the reply")]
+    InvalidParentComment,
+    #[msg("Comment address doesn't match its seeds")]
+    InvalidCommentAddress,
+    #[msg("Tweet address doesn't match its seeds")]
+    InvalidTweetAddress,
+    #[msg("Cannot edit, legacy comment address depends on its content")]
+    LegacyCommentNotEditable,
+    #[msg("Display name too Long")]
+    DisplayNameTooLong,
+    #[msg("Bio too Long")]
+    BioTooLong,
+    #[msg("Avatar URI too Long")]
+    AvatarUriTooLong,
+    #[msg("Handle must be 1 to 15 letters, digits or underscores")]
+    InvalidHandle,
+    #[msg("Profile already has a handle, change it instead")]
+    HandleAlreadySet,
+    #[msg("Profile has no handle")]
+    NoHandleSet,
+    #[msg("Profile does not belong to the tweet author")]
+    InvalidProfile,
+    #[msg("New handle account is required unless only the casing changes")]
+    HandleAccountRequired,
+    #[msg("Maximum number of Tweets Reached")]
+    MaxTweetsReached,
+    #[msg("Cannot follow yourself")]
+    CannotFollowSelf,
+    #[msg("Maximum number of Followers Reached")]
+    MaxFollowersReached,
+    #[msg("Minimum number of Followers Reached")]
+    MinFollowersReached,
+    #[msg("Maximum number of Following Reached")]
+    MaxFollowingReached,
+    #[msg("Minimum number of Following Reached")]
+    MinFollowingReached,
+    #[msg("Maximum number of Retweets Reached")]
+    MaxRetweetsReached,
+    #[msg("Minimum number of Retweets Reached")]
+    MinRetweetsReached,
+    #[msg("Cannot delete, tweet still has retweets")]
+    TweetHasRetweets,
+    #[msg("Tip amount must be greater than zero")]
+    InvalidTipAmount,
+    #[msg("Cannot tip your own tweet")]
+    CannotTipSelf,
+    #[msg("Maximum number of Tips Reached")]
+    MaxTipsReached,
+    #[msg("Too many moderators")]
+    TooManyModerators,
+    #[msg("Signer is not a moderator")]
+    NotModerator,
+    #[msg("Signer is not the program's upgrade authority")]
+    NotUpgradeAuthority,
+    #[msg("Tweet is hidden by a moderator")]
+    TweetHidden,
+    #[msg("Comment is hidden by a moderator")]
+    CommentHidden,
+    #[msg("Maximum number of Reports Reached")]
+    MaxReportsReached,
+    #[msg("Minimum number of Reports Reached")]
+    MinReportsReached,
+    #[msg("Only the reporter or a moderator can close a report")]
+    CannotCloseReport,
+    #[msg("Cannot delete, tweet still has open reports")]
+    TweetHasReports,
+    #[msg("Account already has the current layout")]
+    AccountUpToDate,
 }
@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+
+#[event]
+pub struct TweetEditedEvent {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+    pub version: u32,
+    pub old_content_hash: [u8; 32],
+    pub edited_at: i64,
+}
+
+#[event]
+pub struct TweetDeletedEvent {
+    pub tweet: Pubkey,
+    pub tweet_author: Pubkey,
+}
+
+#[event]
+pub struct CommentEditedEvent {
+    pub comment: Pubkey,
+    pub comment_author: Pubkey,
+    pub parent_tweet: Pubkey,
+    pub old_content_hash: [u8; 32],
+    pub edited_at: i64,
+}
+
+#[event]
+pub struct FollowEvent {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub followed_at: i64,
+}
+
+#[event]
+pub struct UnfollowEvent {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+}
+
+#[event]
+pub struct TipEvent {
+    pub tweet: Pubkey,
+    pub tipper: Pubkey,
+    pub tweet_author: Pubkey,
+    pub amount: u64,
+    pub total_tips: u64,
+}
+
+#[event]
+pub struct ModeratorsUpdatedEvent {
+    pub config: Pubkey,
+    pub admin: Pubkey,
+    pub moderators: Vec<Pubkey>,
+}
+
+#[event]
+pub struct TweetReportedEvent {
+    pub tweet: Pubkey,
+    pub reporter: Pubkey,
+    pub report_count: u64,
+}
+
+#[event]
+pub struct ReportClosedEvent {
+    pub tweet: Pubkey,
+    pub reporter: Pubkey,
+    pub closed_by: Pubkey,
+    pub report_count: u64,
+}
+
+#[event]
+pub struct AdminTransferredEvent {
+    pub config: Pubkey,
+    pub previous_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct TweetHiddenEvent {
+    pub tweet: Pubkey,
+    pub moderator: Pubkey,
+    pub hidden: bool,
+}
+
+#[event]
+pub struct CommentHiddenEvent {
+    pub comment: Pubkey,
+    pub moderator: Pubkey,
+    pub hidden: bool,
+}
@@ -6,12 +6,12 @@
 /// - Validate that comment content doesn't exceed maximum length
 /// - Initialize a new comment account with proper PDA seeds
 /// - Set comment fields: content, author, parent tweet, and bump
-/// - Use content hash in PDA seeds for unique comment identification
+/// - Use the tweet's next comment id in PDA seeds, so the same text can be posted twice
+/// - Increment the comment counter and the next comment id on the tweet
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 
 use crate::errors::TwitterError;
 use crate::states::*;
@@ -20,18 +20,28 @@ pub fn add_comment(ctx: Context<AddCommentContext>, comment_content: String) ->
     require!(comment_content.len() <= COMMENT_LENGTH, TwitterError::CommentTooLong);
 
     let comment_author = &ctx.accounts.comment_author;
-    let tweet = &ctx.accounts.tweet;
+    let tweet = &mut ctx.accounts.tweet;
+    require!(!tweet.hidden, TwitterError::TweetHidden);
 
     let comment = &mut ctx.accounts.comment;
     comment.comment_author = comment_author.key();
     comment.content = comment_content;
     comment.parent_tweet = tweet.key();
     comment.bump = ctx.bumps.comment;
+    comment.parent_comment = None;
+    comment.reply_count = 0;
+    comment.comment_id = Some(tweet.next_comment_id);
+    comment.edited = false;
+    comment.edited_at = 0;
+    comment.hidden = false;
+
+    require!(tweet.next_comment_id < u64::MAX, TwitterError::MaxCommentsReached);
+    tweet.comment_count += 1;
+    tweet.next_comment_id += 1;
     Ok(())
 }
 
 #[derive(Accounts)]
-#[instruction(comment_content: String)]
 pub struct AddCommentContext<'info> {
     #[account(mut)]
     pub comment_author: Signer<'info>,
@@ -42,9 +52,8 @@ pub struct AddCommentContext<'info> {
         space = 8 + Comment::INIT_SPACE,
         seeds = [
             COMMENT_SEED.as_bytes(),
-            comment_author.key().as_ref(),
-            {hash(comment_content.as_bytes()).to_bytes().as_ref()},
-            tweet.key().as_ref()
+            tweet.key().as_ref(),
+            tweet.next_comment_id.to_le_bytes().as_ref()
         ],
         bump
     )]
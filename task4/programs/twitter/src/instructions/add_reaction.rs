@@ -4,9 +4,10 @@
 /// 
 /// Requirements:
 /// - Initialize a new reaction account with proper PDA seeds
-/// - Increment the appropriate counter (likes or dislikes) on the tweet
+/// - Increment the counter for the reaction type on the tweet
 /// - Set reaction fields: type, author, parent tweet, and bump
-/// - Handle both Like and Dislike reaction types
+/// - Handle every reaction type, reporting the matching max error
+/// - Refuse reactions on tweets hidden by a moderator
 /// 
 ///-------------------------------------------------------------------------------
 
@@ -18,6 +19,7 @@ use crate::states::*;
 pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) -> Result<()> {
     let reaction_author: &Signer = &ctx.accounts.reaction_author;
     let tweet = &mut ctx.accounts.tweet;
+    require!(!tweet.hidden, TwitterError::TweetHidden);
 
     let tweet_reaction = &mut ctx.accounts.tweet_reaction;
     tweet_reaction.reaction_author = reaction_author.key();
@@ -25,17 +27,8 @@ pub fn add_reaction(ctx: Context<AddReactionContext>, reaction: ReactionType) ->
     tweet_reaction.parent_tweet = tweet.key();
     tweet_reaction.bump = ctx.bumps.tweet_reaction;
 
-    // Increment the appropriate counter based on reaction type
-    match tweet_reaction.reaction {
-        ReactionType::Like => {
-            require!(tweet.likes < u64::MAX, TwitterError::MaxLikesReached);
-            tweet.likes += 1;
-        },
-        ReactionType::Dislike => {
-            require!(tweet.dislikes < u64::MAX, TwitterError::MaxLikesReached);
-            tweet.dislikes += 1;
-        },
-    }
+    // Increment the counter for the reaction type
+    tweet.add_reaction(reaction)?;
     Ok(())
 }
 
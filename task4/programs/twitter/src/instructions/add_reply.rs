@@ -0,0 +1,74 @@
+//-------------------------------------------------------------------------------
+//
+// Add Reply
+//
+// - Validate that reply content doesn't exceed maximum length
+// - Initialize a new comment account seeded by the tweet's next comment id;
+//   the parent comment link alone makes the thread, so it can nest to any depth
+// - Keep the thread's tweet as `parent_tweet` and link the parent comment
+// - Increment the reply counter on the parent comment, and the comment
+//   counter and next comment id on the tweet
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn add_reply(ctx: Context<AddReplyContext>, comment_content: String) -> Result<()> {
+    require!(comment_content.len() <= COMMENT_LENGTH, TwitterError::CommentTooLong);
+
+    let comment_author = &ctx.accounts.comment_author;
+    let parent_comment = &mut ctx.accounts.parent_comment;
+    let tweet = &mut ctx.accounts.tweet;
+    require!(!tweet.hidden, TwitterError::TweetHidden);
+    require!(!parent_comment.hidden, TwitterError::CommentHidden);
+
+    let comment = &mut ctx.accounts.comment;
+    comment.comment_author = comment_author.key();
+    comment.content = comment_content;
+    comment.parent_tweet = tweet.key();
+    comment.bump = ctx.bumps.comment;
+    comment.parent_comment = Some(parent_comment.key());
+    comment.reply_count = 0;
+    comment.comment_id = Some(tweet.next_comment_id);
+    comment.edited = false;
+    comment.edited_at = 0;
+    comment.hidden = false;
+
+    require!(parent_comment.reply_count < u64::MAX, TwitterError::MaxRepliesReached);
+    parent_comment.reply_count += 1;
+
+    require!(tweet.next_comment_id < u64::MAX, TwitterError::MaxCommentsReached);
+    tweet.comment_count += 1;
+    tweet.next_comment_id += 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddReplyContext<'info> {
+    #[account(mut)]
+    pub comment_author: Signer<'info>,
+    #[account(
+        init,
+        payer = comment_author,
+        // space = discriminant + account size
+        space = 8 + Comment::INIT_SPACE,
+        seeds = [
+            COMMENT_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            tweet.next_comment_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub comment: Account<'info, Comment>,
+    #[account(mut)]
+    pub parent_comment: Account<'info, Comment>,
+    #[account(
+        mut,
+        address = parent_comment.parent_tweet, // Ensure the reply stays in the parent's thread
+    )]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
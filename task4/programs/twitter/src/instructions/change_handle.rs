@@ -0,0 +1,77 @@
+//-------------------------------------------------------------------------------
+//
+// Change Handle
+//
+// - Only the profile authority can change the handle
+// - The profile must already hold a handle
+// - Claim the new registry entry and close the old one in the same transaction,
+//   returning its rent to the authority
+// - A change in casing only keeps the registry entry, which is keyed by the
+//   normalized handle, and updates the profile in place
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn change_handle(ctx: Context<ChangeHandleContext>, handle: String) -> Result<()> {
+    validate_handle(&handle)?;
+
+    let profile = &mut ctx.accounts.profile;
+    require!(profile.handle.is_some(), TwitterError::NoHandleSet);
+
+    let normalized_handle = normalize_handle(&handle);
+    if normalized_handle != ctx.accounts.old_handle_account.handle {
+        let (new_handle_account, bump) = ctx
+            .accounts
+            .new_handle_account
+            .as_mut()
+            .zip(ctx.bumps.new_handle_account)
+            .ok_or(TwitterError::HandleAccountRequired)?;
+
+        new_handle_account.authority = profile.authority;
+        new_handle_account.handle = normalized_handle;
+        new_handle_account.bump = bump;
+
+        // Close the old registry entry and return rent to the authority
+        ctx.accounts
+            .old_handle_account
+            .close(ctx.accounts.authority.to_account_info())?;
+    }
+
+    profile.handle = Some(handle);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct ChangeHandleContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [PROFILE_SEED.as_bytes(), authority.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [HANDLE_SEED.as_bytes(), old_handle_account.handle.as_bytes()],
+        bump = old_handle_account.bump
+    )]
+    pub old_handle_account: Account<'info, Handle>,
+    // Omitted when only the casing changes, as it would be the old entry again
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Handle::INIT_SPACE,
+        seeds = [HANDLE_SEED.as_bytes(), normalize_handle(&handle).as_bytes()],
+        bump
+    )]
+    pub new_handle_account: Option<Account<'info, Handle>>,
+    pub system_program: Program<'info, System>,
+}
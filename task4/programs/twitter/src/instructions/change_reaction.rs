@@ -0,0 +1,49 @@
+//-------------------------------------------------------------------------------
+//
+// Change Reaction
+//
+// - Only the reaction author can change the reaction
+// - Fail if the new reaction type is the same as the current one
+// - Refuse changes on tweets hidden by a moderator
+// - Update the existing reaction PDA in place, no new rent is paid
+// - Move one count from the old reaction counter to the new one on the tweet
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn change_reaction(ctx: Context<ChangeReactionContext>, reaction: ReactionType) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    let tweet_reaction = &mut ctx.accounts.tweet_reaction;
+
+    require!(!tweet.hidden, TwitterError::TweetHidden);
+    require!(tweet_reaction.reaction != reaction, TwitterError::SameReaction);
+
+    // Move one count from the current reaction type to the new one
+    tweet.remove_reaction(tweet_reaction.reaction)?;
+    tweet.add_reaction(reaction)?;
+
+    tweet_reaction.reaction = reaction;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ChangeReactionContext<'info> {
+    pub reaction_author: Signer<'info>,
+    #[account(
+        mut,
+        constraint = tweet_reaction.reaction_author == reaction_author.key(), // Ensure the reaction belongs to the author
+        seeds = [
+            TWEET_REACTION_SEED.as_bytes(),
+            reaction_author.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = tweet_reaction.bump
+    )]
+    pub tweet_reaction: Account<'info, Reaction>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,55 @@
+//-------------------------------------------------------------------------------
+//
+// Claim Handle
+//
+// - Only the profile authority can claim a handle for the profile
+// - A profile holds at most one handle; use change handle to replace it
+// - Handles are 1 to 15 letters, digits or underscores
+// - The registry PDA is seeded by the lowercase handle, so "Bob" and "bob"
+//   collide and a taken handle fails on account initialization
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn claim_handle(ctx: Context<ClaimHandleContext>, handle: String) -> Result<()> {
+    validate_handle(&handle)?;
+
+    let profile = &mut ctx.accounts.profile;
+    require!(profile.handle.is_none(), TwitterError::HandleAlreadySet);
+
+    let handle_account = &mut ctx.accounts.handle_account;
+
+    handle_account.authority = profile.authority;
+    handle_account.handle = normalize_handle(&handle);
+    handle_account.bump = ctx.bumps.handle_account;
+
+    profile.handle = Some(handle);
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(handle: String)]
+pub struct ClaimHandleContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [PROFILE_SEED.as_bytes(), authority.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Handle::INIT_SPACE,
+        seeds = [HANDLE_SEED.as_bytes(), normalize_handle(&handle).as_bytes()],
+        bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+    pub system_program: Program<'info, System>,
+}
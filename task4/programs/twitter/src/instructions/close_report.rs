@@ -0,0 +1,71 @@
+//-------------------------------------------------------------------------------
+//
+// Close Report
+//
+// - The reporter can withdraw their report, and the admin or a moderator listed
+//   in the config can close it once the report is resolved
+// - Close the report PDA and return its rent to the reporter
+// - Decrement the report counter on the tweet, so it can be deleted again once
+//   every report is closed
+// - Emit a report closed event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::ReportClosedEvent;
+use crate::states::*;
+
+pub fn close_report(ctx: Context<CloseReportContext>) -> Result<()> {
+    let closer = ctx.accounts.closer.key();
+    let is_moderator = ctx
+        .accounts
+        .config
+        .as_ref()
+        .is_some_and(|config| config.is_moderator(&closer));
+    require!(
+        closer == ctx.accounts.reporter.key() || is_moderator,
+        TwitterError::CannotCloseReport
+    );
+
+    let tweet = &mut ctx.accounts.tweet;
+    require!(tweet.report_count > 0, TwitterError::MinReportsReached);
+    tweet.report_count -= 1;
+
+    emit!(ReportClosedEvent {
+        tweet: tweet.key(),
+        reporter: ctx.accounts.reporter.key(),
+        closed_by: closer,
+        report_count: tweet.report_count,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseReportContext<'info> {
+    pub closer: Signer<'info>,
+    // Only needed when a moderator closes the report
+    #[account(
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Option<Account<'info, Config>>,
+    #[account(mut)]
+    pub reporter: SystemAccount<'info>,
+    #[account(
+        mut,
+        close = reporter,
+        has_one = reporter,
+        has_one = tweet,
+        seeds = [
+            REPORT_SEED.as_bytes(),
+            reporter.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = report.bump
+    )]
+    pub report: Account<'info, Report>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,48 @@
+//-------------------------------------------------------------------------------
+//
+// Close Tweet Version
+//
+// - Only the tweet author can close archived versions of the tweet
+// - Versions are closed oldest first, one per instruction, so a tweet with
+//   more versions than fit in a single transaction can be deleted in batches
+// - Close the version account and return rent to the author
+// - Count the closed version on the tweet; delete_tweet then only expects the
+//   versions that are still open
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn close_tweet_version(ctx: Context<CloseTweetVersionContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    // The version PDA only exists below `tweet.version`, so this cannot pass it
+    tweet.closed_versions += 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseTweetVersionContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = tweet_author, // Ensure only the author can close versions of the tweet
+        constraint = tweet.is_address(&tweet.key()) @ TwitterError::InvalidTweetAddress, // Check seeds under either scheme
+    )]
+    pub tweet: Account<'info, Tweet>,
+    #[account(
+        mut,
+        close = tweet_author, // Close the version account and return rent to the author
+        has_one = tweet, // Ensure the version belongs to the tweet
+        seeds = [
+            TWEET_VERSION_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            tweet.closed_versions.to_le_bytes().as_ref(),
+        ],
+        bump = tweet_version.bump
+    )]
+    pub tweet_version: Account<'info, TweetVersion>,
+}
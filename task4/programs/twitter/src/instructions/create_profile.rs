@@ -0,0 +1,50 @@
+//-------------------------------------------------------------------------------
+//
+// Create Profile
+//
+// - One profile per user, seeded by the authority's key
+// - Validate display name, bio and avatar URI against their maximum lengths
+// - The profile starts without a handle and with zeroed tweet and follow counters
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+pub fn create_profile(
+    ctx: Context<CreateProfileContext>,
+    display_name: String,
+    bio: String,
+    avatar_uri: String,
+) -> Result<()> {
+    Profile::validate(&display_name, &bio, &avatar_uri)?;
+
+    let profile = &mut ctx.accounts.profile;
+
+    profile.authority = ctx.accounts.authority.key();
+    profile.display_name = display_name;
+    profile.bio = bio;
+    profile.avatar_uri = avatar_uri;
+    profile.handle = None;
+    profile.tweet_count = 0;
+    profile.followers = 0;
+    profile.following = 0;
+    profile.bump = ctx.bumps.profile;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CreateProfileContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + Profile::INIT_SPACE,
+        seeds = [PROFILE_SEED.as_bytes(), authority.key().as_ref()],
+        bump
+    )]
+    pub profile: Account<'info, Profile>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,69 @@
+//-------------------------------------------------------------------------------
+//
+// Delete Tweet
+//
+// - Only the tweet author can delete the tweet
+// - Accept tweets under both the sequential and the legacy topic seeds
+// - Deletion is refused while the tweet still has reactions, comments,
+//   retweets or open reports, so no Reaction, Comment, Retweet or Report PDA is
+//   ever left pointing at a closed tweet (or at a new tweet later created under
+//   the same address)
+// - Every archived version PDA not yet closed through close_tweet_version must
+//   be passed in remaining accounts, in order; they are closed together with
+//   the tweet
+// - Close the tweet account and return rent to the author
+// - Emit a tweet deleted event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetDeletedEvent;
+use crate::states::*;
+
+pub fn delete_tweet<'info>(
+    ctx: Context<'_, '_, 'info, 'info, DeleteTweetContext<'info>>,
+) -> Result<()> {
+    let tweet = &ctx.accounts.tweet;
+    let tweet_author = &ctx.accounts.tweet_author;
+
+    require!(!tweet.has_reactions(), TwitterError::TweetHasReactions);
+    require!(tweet.comment_count == 0, TwitterError::TweetHasComments);
+    require!(tweet.retweets == 0, TwitterError::TweetHasRetweets);
+    require!(tweet.report_count == 0, TwitterError::TweetHasReports);
+
+    // Close every archived version of the tweet still open
+    let open_versions = (tweet.closed_versions..tweet.version).zip(ctx.remaining_accounts);
+    require!(
+        ctx.remaining_accounts.len() == (tweet.version - tweet.closed_versions) as usize,
+        TwitterError::InvalidTweetVersions
+    );
+    for (version, account_info) in open_versions {
+        let tweet_version = Account::<TweetVersion>::try_from(account_info)?;
+        require!(
+            tweet_version.tweet == tweet.key() && tweet_version.version == version,
+            TwitterError::InvalidTweetVersions
+        );
+        tweet_version.close(tweet_author.to_account_info())?;
+    }
+
+    emit!(TweetDeletedEvent {
+        tweet: tweet.key(),
+        tweet_author: tweet_author.key(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct DeleteTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+    #[account(
+        mut,
+        close = tweet_author, // Close the tweet account and return rent to the author
+        has_one = tweet_author, // Ensure only the author can delete the tweet
+        constraint = tweet.is_address(&tweet.key()) @ TwitterError::InvalidTweetAddress, // Check seeds under either scheme
+    )]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,53 @@
+//-------------------------------------------------------------------------------
+//
+// Edit Comment
+//
+// - Only the comment author can edit the comment
+// - Validate that the new content doesn't exceed the maximum length
+// - Only comments with a sequential id can be edited; legacy comments are
+//   seeded by their content hash, so new content would break their address
+// - Replace the content, set the edited flag and record the edit time
+// - Emit an event carrying the hash of the previous content
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::TwitterError;
+use crate::events::CommentEditedEvent;
+use crate::states::*;
+
+pub fn edit_comment(ctx: Context<EditCommentContext>, comment_content: String) -> Result<()> {
+    require!(comment_content.len() <= COMMENT_LENGTH, TwitterError::CommentTooLong);
+
+    let comment = &mut ctx.accounts.comment;
+    require!(comment.comment_id.is_some(), TwitterError::LegacyCommentNotEditable);
+
+    let old_content_hash = hash(comment.content.as_bytes()).to_bytes();
+    let edited_at = Clock::get()?.unix_timestamp;
+
+    comment.content = comment_content;
+    comment.edited = true;
+    comment.edited_at = edited_at;
+
+    emit!(CommentEditedEvent {
+        comment: comment.key(),
+        comment_author: comment.comment_author,
+        parent_tweet: comment.parent_tweet,
+        old_content_hash,
+        edited_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EditCommentContext<'info> {
+    pub comment_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = comment_author, // Ensure only the author can edit the comment
+        constraint = comment.is_address(&comment.key()) @ TwitterError::InvalidCommentAddress,
+    )]
+    pub comment: Account<'info, Comment>,
+}
@@ -0,0 +1,71 @@
+//-------------------------------------------------------------------------------
+//
+// Edit Tweet
+//
+// - Only the tweet author can edit the tweet
+// - Accept tweets under both the sequential and the legacy topic seeds
+// - Validate that the new content doesn't exceed the maximum length
+// - Store the previous content in a version PDA, so readers can audit edits
+// - Replace the content, increment the version and record the edit time
+// - Emit an event carrying the hash of the previous content
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+
+use crate::errors::TwitterError;
+use crate::events::TweetEditedEvent;
+use crate::states::*;
+
+pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+    require!(content.len() <= CONTENT_LENGTH, TwitterError::ContentTooLong);
+
+    let tweet = &mut ctx.accounts.tweet;
+    let edited_at = Clock::get()?.unix_timestamp;
+
+    // Keep the replaced content under the version it was published as
+    let tweet_version = &mut ctx.accounts.tweet_version;
+    tweet_version.tweet = tweet.key();
+    tweet_version.version = tweet.version;
+    tweet_version.content = std::mem::replace(&mut tweet.content, content);
+    tweet_version.bump = ctx.bumps.tweet_version;
+
+    tweet.version = tweet.version.checked_add(1).ok_or(TwitterError::MaxEditsReached)?;
+    tweet.edited_at = edited_at;
+
+    emit!(TweetEditedEvent {
+        tweet: tweet.key(),
+        tweet_author: tweet.tweet_author,
+        version: tweet.version,
+        old_content_hash: hash(tweet_version.content.as_bytes()).to_bytes(),
+        edited_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct EditTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_author: Signer<'info>,
+    #[account(
+        mut,
+        has_one = tweet_author, // Ensure only the author can edit the tweet
+        constraint = tweet.is_address(&tweet.key()) @ TwitterError::InvalidTweetAddress, // Check seeds under either scheme
+    )]
+    pub tweet: Account<'info, Tweet>,
+    #[account(
+        init,
+        payer = tweet_author,
+        // space = discriminant + account size
+        space = 8 + TweetVersion::INIT_SPACE,
+        seeds = [
+            TWEET_VERSION_SEED.as_bytes(),
+            tweet.key().as_ref(),
+            tweet.version.to_le_bytes().as_ref(),
+        ],
+        bump
+    )]
+    pub tweet_version: Account<'info, TweetVersion>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,74 @@
+//-------------------------------------------------------------------------------
+//
+// Follow
+//
+// - Both the follower and the followee need a profile
+// - A user cannot follow themselves
+// - One follow PDA per follower and followee, so following twice fails
+// - Increment the follower's following count and the followee's followers count
+// - Emit an event for feed indexers
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::FollowEvent;
+use crate::states::*;
+
+pub fn follow(ctx: Context<FollowContext>) -> Result<()> {
+    let follower_profile = &mut ctx.accounts.follower_profile;
+    let followee_profile = &mut ctx.accounts.followee_profile;
+
+    require!(follower_profile.following < u64::MAX, TwitterError::MaxFollowingReached);
+    require!(followee_profile.followers < u64::MAX, TwitterError::MaxFollowersReached);
+    follower_profile.following += 1;
+    followee_profile.followers += 1;
+
+    let followed_at = Clock::get()?.unix_timestamp;
+    let follow = &mut ctx.accounts.follow;
+
+    follow.follower = follower_profile.authority;
+    follow.followee = followee_profile.authority;
+    follow.followed_at = followed_at;
+    follow.bump = ctx.bumps.follow;
+
+    emit!(FollowEvent {
+        follower: follow.follower,
+        followee: follow.followee,
+        followed_at,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct FollowContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_bytes(), follower.key().as_ref()],
+        bump = follower_profile.bump
+    )]
+    pub follower_profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        constraint = followee_profile.authority != follower.key() @ TwitterError::CannotFollowSelf,
+        seeds = [PROFILE_SEED.as_bytes(), followee_profile.authority.as_ref()],
+        bump = followee_profile.bump
+    )]
+    pub followee_profile: Account<'info, Profile>,
+    #[account(
+        init,
+        payer = follower,
+        space = 8 + Follow::INIT_SPACE,
+        seeds = [
+            FOLLOW_SEED.as_bytes(),
+            follower.key().as_ref(),
+            followee_profile.authority.as_ref()
+        ],
+        bump
+    )]
+    pub follow: Account<'info, Follow>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,57 @@
+//-------------------------------------------------------------------------------
+//
+// Initialize Config
+//
+// - Create the single program config account
+// - Only the program's upgrade authority can initialize it and becomes the
+//   admin; the admin can later be handed over with transfer_admin
+// - Store up to MAX_MODERATORS moderator keys
+// - Emit a moderators updated event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::ModeratorsUpdatedEvent;
+use crate::states::*;
+
+pub fn initialize_config(ctx: Context<InitializeConfigContext>, moderators: Vec<Pubkey>) -> Result<()> {
+    require!(moderators.len() <= MAX_MODERATORS, TwitterError::TooManyModerators);
+
+    let config = &mut ctx.accounts.config;
+
+    config.admin = ctx.accounts.admin.key();
+    config.moderators = moderators;
+    config.bump = ctx.bumps.config;
+
+    emit!(ModeratorsUpdatedEvent {
+        config: config.key(),
+        admin: config.admin,
+        moderators: config.moderators.clone(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct InitializeConfigContext<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        constraint = program.programdata_address()? == Some(program_data.key()) @ TwitterError::NotUpgradeAuthority, // Ensure the program data belongs to this program
+    )]
+    pub program: Program<'info, crate::program::Twitter>,
+    #[account(
+        constraint = program_data.upgrade_authority_address == Some(admin.key()) @ TwitterError::NotUpgradeAuthority, // Ensure the deployer becomes the admin
+    )]
+    pub program_data: Account<'info, ProgramData>,
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Config::INIT_SPACE,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump
+    )]
+    pub config: Account<'info, Config>,
+    pub system_program: Program<'info, System>,
+}
@@ -5,9 +5,12 @@
 /// Requirements:
 /// - Validate that topic and content don't exceed maximum lengths
 /// - Initialize a new tweet account with proper PDA seeds
-/// - Set tweet fields: topic, content, author, likes, dislikes, and bump
-/// - Initialize counters (likes and dislikes) to zero
-/// - Use topic in PDA seeds for tweet identification
+/// - Set tweet fields: topic, content, author, reaction counters, and bump
+/// - Initialize every reaction counter to zero
+/// - Seed the tweet with the author's next tweet id, so an author can post
+///   any number of tweets on the same topic
+/// - Increment the author's profile tweet count when a profile is passed
+/// - Create the topic's Topic PDA on its first tweet and record every tweet on it
 /// 
 ///-------------------------------------------------------------------------------
 
@@ -21,18 +24,26 @@ pub fn initialize_tweet(
     topic: String,
     content: String,
 ) -> Result<()> {
-    require!(topic.len() <= TOPIC_LENGTH, TwitterError::TopicTooLong);
-    require!(content.len() <= COMMENT_LENGTH, TwitterError::ContentTooLong);
-    let author: &Signer = &ctx.accounts.tweet_authority;
+    let tweet_counter = &mut ctx.accounts.tweet_counter;
+    tweet_counter.author = ctx.accounts.tweet_authority.key();
+    tweet_counter.bump = ctx.bumps.tweet_counter;
 
-    let tweet = &mut ctx.accounts.tweet;
+    let topic_account = &mut ctx.accounts.topic_account;
+    topic_account.bump = ctx.bumps.topic_account;
 
-    tweet.tweet_author = author.key();
-    tweet.topic = topic;
-    tweet.content = content;
-    tweet.likes = 0;
-    tweet.dislikes = 0;
-    tweet.bump = ctx.bumps.tweet;
+    ctx.accounts.tweet.init(
+        topic,
+        content,
+        None,
+        ctx.bumps.tweet,
+        tweet_counter,
+        topic_account,
+    )?;
+
+    if let Some(profile) = ctx.accounts.profile.as_mut() {
+        require!(profile.tweet_count < u64::MAX, TwitterError::MaxTweetsReached);
+        profile.tweet_count += 1;
+    }
     Ok(())
 }
 
@@ -41,17 +52,39 @@ pub fn initialize_tweet(
 pub struct InitializeTweet<'info> {
     #[account(mut)]
     pub tweet_authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + TweetCounter::INIT_SPACE,
+        seeds = [TWEET_COUNTER_SEED.as_bytes(), tweet_authority.key().as_ref()],
+        bump
+    )]
+    pub tweet_counter: Account<'info, TweetCounter>,
     #[account(
         init, 
         payer = tweet_authority, 
         space = 8 + Tweet::INIT_SPACE,
         seeds = [
-            topic.as_bytes(),
             TWEET_SEED.as_bytes(),
-            tweet_authority.key().as_ref()
+            tweet_authority.key().as_ref(),
+            tweet_counter.next_tweet_id.to_le_bytes().as_ref()
         ],
         bump
     )]
     pub tweet: Account<'info, Tweet>,
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + Topic::INIT_SPACE,
+        seeds = [TOPIC_SEED.as_bytes(), topic_seed(&topic).as_ref()],
+        bump
+    )]
+    pub topic_account: Account<'info, Topic>,
+    // Optional so authors without a profile can still tweet
+    #[account(
+        mut,
+        constraint = profile.authority == tweet_authority.key() @ TwitterError::InvalidProfile,
+    )]
+    pub profile: Option<Account<'info, Profile>>,
     pub system_program: Program<'info, System>,
 }
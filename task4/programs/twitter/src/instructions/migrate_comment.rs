@@ -0,0 +1,39 @@
+//-------------------------------------------------------------------------------
+//
+// Migrate Comment
+//
+// - Comments allocated by earlier versions of the program are smaller than the
+//   current layout, so long ones no longer deserialize
+// - Anyone can grow such a comment to the current size and pay the extra rent
+// - Check the comment address under either seed scheme
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn migrate_comment(ctx: Context<MigrateCommentContext>) -> Result<()> {
+    let comment = grow_legacy_account::<Comment>(
+        &ctx.accounts.comment,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Comment::INIT_SPACE,
+    )?;
+    require!(
+        comment.is_address(ctx.accounts.comment.key),
+        TwitterError::InvalidCommentAddress
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateCommentContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded as a Comment, discriminator included, before it is grown
+    #[account(mut, owner = crate::ID)]
+    pub comment: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
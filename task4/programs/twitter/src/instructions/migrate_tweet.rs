@@ -0,0 +1,39 @@
+//-------------------------------------------------------------------------------
+//
+// Migrate Tweet
+//
+// - Tweets allocated by earlier versions of the program are smaller than the
+//   current layout, so long ones no longer deserialize
+// - Anyone can grow such a tweet to the current size and pay the extra rent
+// - Check the tweet address under either seed scheme
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn migrate_tweet(ctx: Context<MigrateTweetContext>) -> Result<()> {
+    let tweet = grow_legacy_account::<Tweet>(
+        &ctx.accounts.tweet,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        8 + Tweet::INIT_SPACE,
+    )?;
+    require!(
+        tweet.is_address(ctx.accounts.tweet.key),
+        TwitterError::InvalidTweetAddress
+    );
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateTweetContext<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    /// CHECK: decoded as a Tweet, discriminator included, before it is grown
+    #[account(mut, owner = crate::ID)]
+    pub tweet: UncheckedAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
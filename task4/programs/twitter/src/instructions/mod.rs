@@ -12,3 +12,81 @@ pub mod add_comment;
 
 pub use remove_comment::*;
 pub mod remove_comment;
+
+pub use edit_tweet::*;
+pub mod edit_tweet;
+
+pub use delete_tweet::*;
+pub mod delete_tweet;
+
+pub use close_tweet_version::*;
+pub mod close_tweet_version;
+
+pub use migrate_tweet::*;
+pub mod migrate_tweet;
+
+pub use change_reaction::*;
+pub mod change_reaction;
+
+pub use add_reply::*;
+pub mod add_reply;
+
+pub use edit_comment::*;
+pub mod edit_comment;
+
+pub use migrate_comment::*;
+pub mod migrate_comment;
+
+pub use create_profile::*;
+pub mod create_profile;
+
+pub use update_profile::*;
+pub mod update_profile;
+
+pub use claim_handle::*;
+pub mod claim_handle;
+
+pub use change_handle::*;
+pub mod change_handle;
+
+pub use release_handle::*;
+pub mod release_handle;
+
+pub use follow::*;
+pub mod follow;
+
+pub use unfollow::*;
+pub mod unfollow;
+
+pub use retweet::*;
+pub mod retweet;
+
+pub use undo_retweet::*;
+pub mod undo_retweet;
+
+pub use quote_tweet::*;
+pub mod quote_tweet;
+
+pub use tip_tweet::*;
+pub mod tip_tweet;
+
+pub use initialize_config::*;
+pub mod initialize_config;
+
+pub use update_moderators::*;
+pub mod update_moderators;
+
+pub use transfer_admin::*;
+pub mod transfer_admin;
+
+pub use report_tweet::*;
+pub mod report_tweet;
+
+pub use close_report::*;
+pub mod close_report;
+
+pub use set_tweet_hidden::*;
+pub mod set_tweet_hidden;
+
+pub use set_comment_hidden::*;
+pub mod set_comment_hidden;
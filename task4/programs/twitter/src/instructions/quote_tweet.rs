@@ -0,0 +1,73 @@
+//-------------------------------------------------------------------------------
+//
+// Quote Tweet
+//
+// - Create a new tweet with its own topic and content, seeded with the
+//   author's next tweet id like any other tweet
+// - Reference the original tweet by its address
+// - Refuse to quote a tweet hidden by a moderator
+// - Record the tweet on its topic, creating the Topic PDA if needed
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn quote_tweet(ctx: Context<QuoteTweetContext>, topic: String, content: String) -> Result<()> {
+    require!(!ctx.accounts.quoted_tweet.hidden, TwitterError::TweetHidden);
+
+    let tweet_counter = &mut ctx.accounts.tweet_counter;
+    tweet_counter.author = ctx.accounts.tweet_authority.key();
+    tweet_counter.bump = ctx.bumps.tweet_counter;
+
+    let topic_account = &mut ctx.accounts.topic_account;
+    topic_account.bump = ctx.bumps.topic_account;
+
+    ctx.accounts.tweet.init(
+        topic,
+        content,
+        Some(ctx.accounts.quoted_tweet.key()),
+        ctx.bumps.tweet,
+        tweet_counter,
+        topic_account,
+    )
+}
+
+#[derive(Accounts)]
+#[instruction(topic: String)]
+pub struct QuoteTweetContext<'info> {
+    #[account(mut)]
+    pub tweet_authority: Signer<'info>,
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + TweetCounter::INIT_SPACE,
+        seeds = [TWEET_COUNTER_SEED.as_bytes(), tweet_authority.key().as_ref()],
+        bump
+    )]
+    pub tweet_counter: Account<'info, TweetCounter>,
+    #[account(
+        init,
+        payer = tweet_authority,
+        space = 8 + Tweet::INIT_SPACE,
+        seeds = [
+            TWEET_SEED.as_bytes(),
+            tweet_authority.key().as_ref(),
+            tweet_counter.next_tweet_id.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub tweet: Account<'info, Tweet>,
+    #[account(
+        init_if_needed,
+        payer = tweet_authority,
+        space = 8 + Topic::INIT_SPACE,
+        seeds = [TOPIC_SEED.as_bytes(), topic_seed(&topic).as_ref()],
+        bump
+    )]
+    pub topic_account: Account<'info, Topic>,
+    pub quoted_tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
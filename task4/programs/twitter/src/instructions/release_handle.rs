@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+//
+// Release Handle
+//
+// - Only the profile authority can release the handle
+// - Close the registry entry so anyone can claim the handle again, returning
+//   its rent to the authority
+// - Clear the handle from the profile
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+pub fn release_handle(ctx: Context<ReleaseHandleContext>) -> Result<()> {
+    ctx.accounts.profile.handle = None;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReleaseHandleContext<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [PROFILE_SEED.as_bytes(), authority.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        close = authority,
+        has_one = authority,
+        seeds = [HANDLE_SEED.as_bytes(), handle_account.handle.as_bytes()],
+        bump = handle_account.bump
+    )]
+    pub handle_account: Account<'info, Handle>,
+}
@@ -4,18 +4,40 @@
 /// 
 /// Requirements:
 /// - Close the comment account and return rent to comment author
-/// 
-/// NOTE: No implementation logic is needed in the function body - this 
-/// functionality is achieved entirely through account constraints!
+/// - Accept comments under both the sequential and the legacy content-hash seeds
+/// - Refuse to remove a comment that still has replies
+/// - Decrement the comment counter on the parent tweet; legacy comments may
+///   predate the counter, so for them it only goes down to zero
+/// - For replies, decrement the reply counter on the parent comment
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::hash::hash;
 
+use crate::errors::TwitterError;
 use crate::states::*;
 
-pub fn remove_comment(_ctx: Context<RemoveCommentContext>) -> Result<()> {
+pub fn remove_comment(ctx: Context<RemoveCommentContext>) -> Result<()> {
+    let comment = &ctx.accounts.comment;
+    let parent_tweet = &mut ctx.accounts.parent_tweet;
+
+    require!(comment.reply_count == 0, TwitterError::CommentHasReplies);
+
+    match (comment.parent_comment, ctx.accounts.parent_comment.as_mut()) {
+        (None, None) => {},
+        (Some(parent), Some(parent_comment)) if parent == parent_comment.key() => {
+            require!(parent_comment.reply_count > 0, TwitterError::MinRepliesReached);
+            parent_comment.reply_count -= 1;
+        },
+        _ => return err!(TwitterError::InvalidParentComment),
+    }
+
+    if comment.comment_id.is_none() {
+        parent_tweet.comment_count = parent_tweet.comment_count.saturating_sub(1);
+        return Ok(());
+    }
+    require!(parent_tweet.comment_count > 0, TwitterError::MinCommentsReached);
+    parent_tweet.comment_count -= 1;
     Ok(())
 }
 
@@ -27,13 +49,13 @@ pub struct RemoveCommentContext<'info> {
         mut,
         close = comment_author, // Close the comment account and return rent to the author
         constraint = comment.comment_author == comment_author.key(), // Ensure the comment belongs to the author
-        seeds = [
-            COMMENT_SEED.as_bytes(),
-            comment_author.key().as_ref(),
-            {hash(comment.content.as_bytes()).to_bytes().as_ref()}, // Use content hash for unique identification
-            comment.parent_tweet.key().as_ref(),
-        ],
-        bump
+        has_one = parent_tweet, // Ensure the counter is updated on the tweet the comment belongs to
+        constraint = comment.is_address(&comment.key()) @ TwitterError::InvalidCommentAddress, // Check seeds under either scheme
     )]
     pub comment: Account<'info, Comment>,
+    #[account(mut)]
+    pub parent_tweet: Account<'info, Tweet>,
+    // Only for replies: the comment being replied to
+    #[account(mut)]
+    pub parent_comment: Option<Account<'info, Comment>>,
 }
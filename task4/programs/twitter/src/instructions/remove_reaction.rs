@@ -4,32 +4,21 @@
 /// 
 /// Requirements:
 /// - Verify that the tweet reaction exists and belongs to the reaction author
-/// - Decrement the appropriate counter (likes or dislikes) on the tweet
+/// - Decrement the counter for the reaction type on the tweet
 /// - Close the tweet reaction account and return rent to reaction author
 /// 
 ///-------------------------------------------------------------------------------
 
 use anchor_lang::prelude::*;
 
-use crate::errors::TwitterError;
 use crate::states::*;
 
 pub fn remove_reaction(ctx: Context<RemoveReactionContext>) -> Result<()> {
     let tweet = &mut ctx.accounts.tweet;
     let tweet_reaction = &ctx.accounts.tweet_reaction;
 
-
-    // Increment the appropriate counter based on reaction type
-    match tweet_reaction.reaction {
-        ReactionType::Like => {
-            require!(tweet.likes > 0, TwitterError::MinLikesReached);
-            tweet.likes -= 1;
-        },
-        ReactionType::Dislike => {
-            require!(tweet.dislikes > 0, TwitterError::MinDislikesReached);
-            tweet.dislikes -= 1;
-        },
-    }
+    // Decrement the counter for the reaction type
+    tweet.remove_reaction(tweet_reaction.reaction)?;
     Ok(())
 }
 
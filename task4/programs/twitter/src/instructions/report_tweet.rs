@@ -0,0 +1,56 @@
+//-------------------------------------------------------------------------------
+//
+// Report Tweet
+//
+// - One report PDA per user and tweet, so reporting twice fails
+// - Increment the report counter on the tweet
+// - Emit a tweet reported event for moderators
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetReportedEvent;
+use crate::states::*;
+
+pub fn report_tweet(ctx: Context<ReportTweetContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    require!(tweet.report_count < u64::MAX, TwitterError::MaxReportsReached);
+    tweet.report_count += 1;
+
+    let report = &mut ctx.accounts.report;
+
+    report.reporter = ctx.accounts.reporter.key();
+    report.tweet = tweet.key();
+    report.reported_at = Clock::get()?.unix_timestamp;
+    report.bump = ctx.bumps.report;
+
+    emit!(TweetReportedEvent {
+        tweet: tweet.key(),
+        reporter: report.reporter,
+        report_count: tweet.report_count,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReportTweetContext<'info> {
+    #[account(mut)]
+    pub reporter: Signer<'info>,
+    #[account(
+        init,
+        payer = reporter,
+        space = 8 + Report::INIT_SPACE,
+        seeds = [
+            REPORT_SEED.as_bytes(),
+            reporter.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub report: Account<'info, Report>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,50 @@
+//-------------------------------------------------------------------------------
+//
+// Retweet
+//
+// - One retweet PDA per user and original tweet, so retweeting twice fails
+// - Refuse to retweet a tweet hidden by a moderator
+// - Increment the retweet counter on the original tweet
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn retweet(ctx: Context<RetweetContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    require!(!tweet.hidden, TwitterError::TweetHidden);
+    require!(tweet.retweets < u64::MAX, TwitterError::MaxRetweetsReached);
+    tweet.retweets += 1;
+
+    let retweet = &mut ctx.accounts.retweet;
+
+    retweet.retweeter = ctx.accounts.retweeter.key();
+    retweet.tweet = tweet.key();
+    retweet.retweeted_at = Clock::get()?.unix_timestamp;
+    retweet.bump = ctx.bumps.retweet;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RetweetContext<'info> {
+    #[account(mut)]
+    pub retweeter: Signer<'info>,
+    #[account(
+        init,
+        payer = retweeter,
+        space = 8 + Retweet::INIT_SPACE,
+        seeds = [
+            RETWEET_SEED.as_bytes(),
+            retweeter.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump
+    )]
+    pub retweet: Account<'info, Retweet>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+//
+// Set Comment Hidden
+//
+// - Only the admin or a moderator listed in the config can hide or unhide a comment
+// - Hidden comments accept no new replies
+// - Emit a comment hidden event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::CommentHiddenEvent;
+use crate::states::*;
+
+pub fn set_comment_hidden(ctx: Context<SetCommentHiddenContext>, hidden: bool) -> Result<()> {
+    let comment = &mut ctx.accounts.comment;
+    comment.hidden = hidden;
+
+    emit!(CommentHiddenEvent {
+        comment: comment.key(),
+        moderator: ctx.accounts.moderator.key(),
+        hidden,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetCommentHiddenContext<'info> {
+    pub moderator: Signer<'info>,
+    #[account(
+        constraint = config.is_moderator(&moderator.key()) @ TwitterError::NotModerator,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub comment: Account<'info, Comment>,
+}
@@ -0,0 +1,40 @@
+//-------------------------------------------------------------------------------
+//
+// Set Tweet Hidden
+//
+// - Only the admin or a moderator listed in the config can hide or unhide a tweet
+// - Hidden tweets accept no new reactions or comments
+// - Emit a tweet hidden event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::TweetHiddenEvent;
+use crate::states::*;
+
+pub fn set_tweet_hidden(ctx: Context<SetTweetHiddenContext>, hidden: bool) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.hidden = hidden;
+
+    emit!(TweetHiddenEvent {
+        tweet: tweet.key(),
+        moderator: ctx.accounts.moderator.key(),
+        hidden,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SetTweetHiddenContext<'info> {
+    pub moderator: Signer<'info>,
+    #[account(
+        constraint = config.is_moderator(&moderator.key()) @ TwitterError::NotModerator,
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,70 @@
+//-------------------------------------------------------------------------------
+//
+// Tip Tweet
+//
+// - Anyone but the author can tip a tweet with a non-zero amount of lamports
+// - The author account must be the tweet's author
+// - Transfer the lamports from the tipper to the author through the system program
+// - Add the amount to the tweet's total tips and increment its tip count
+// - Emit a tip event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::TwitterError;
+use crate::events::TipEvent;
+use crate::states::*;
+
+pub fn tip_tweet(ctx: Context<TipTweetContext>, amount: u64) -> Result<()> {
+    require!(amount > 0, TwitterError::InvalidTipAmount);
+    require!(
+        ctx.accounts.tipper.key() != ctx.accounts.tweet.tweet_author,
+        TwitterError::CannotTipSelf
+    );
+
+    let tweet = &mut ctx.accounts.tweet;
+    tweet.total_tips = tweet
+        .total_tips
+        .checked_add(amount)
+        .ok_or(TwitterError::MaxTipsReached)?;
+    tweet.tip_count = tweet
+        .tip_count
+        .checked_add(1)
+        .ok_or(TwitterError::MaxTipsReached)?;
+
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.tipper.to_account_info(),
+                to: ctx.accounts.tweet_author.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    emit!(TipEvent {
+        tweet: tweet.key(),
+        tipper: ctx.accounts.tipper.key(),
+        tweet_author: tweet.tweet_author,
+        amount,
+        total_tips: tweet.total_tips,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TipTweetContext<'info> {
+    #[account(mut)]
+    pub tipper: Signer<'info>,
+    #[account(
+        mut,
+        has_one = tweet_author, // Ensure the tip goes to the tweet's author
+    )]
+    pub tweet: Account<'info, Tweet>,
+    #[account(mut)]
+    pub tweet_author: SystemAccount<'info>,
+    pub system_program: Program<'info, System>,
+}
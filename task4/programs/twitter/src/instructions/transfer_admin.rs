@@ -0,0 +1,39 @@
+//-------------------------------------------------------------------------------
+//
+// Transfer Admin
+//
+// - Only the admin can hand the config over to a new admin
+// - The previous admin loses its moderation rights unless it is also listed
+//   as a moderator
+// - Emit an admin transferred event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::events::AdminTransferredEvent;
+use crate::states::*;
+
+pub fn transfer_admin(ctx: Context<TransferAdminContext>, new_admin: Pubkey) -> Result<()> {
+    let config = &mut ctx.accounts.config;
+    let previous_admin = std::mem::replace(&mut config.admin, new_admin);
+
+    emit!(AdminTransferredEvent {
+        config: config.key(),
+        previous_admin,
+        new_admin,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct TransferAdminContext<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin, // Ensure only the admin can hand over the config
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
@@ -0,0 +1,42 @@
+//-------------------------------------------------------------------------------
+//
+// Undo Retweet
+//
+// - Only the retweeter can undo their retweet
+// - Close the retweet PDA and return its rent to the retweeter
+// - Decrement the retweet counter on the original tweet
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::states::*;
+
+pub fn undo_retweet(ctx: Context<UndoRetweetContext>) -> Result<()> {
+    let tweet = &mut ctx.accounts.tweet;
+    require!(tweet.retweets > 0, TwitterError::MinRetweetsReached);
+    tweet.retweets -= 1;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UndoRetweetContext<'info> {
+    #[account(mut)]
+    pub retweeter: Signer<'info>,
+    #[account(
+        mut,
+        close = retweeter,
+        has_one = retweeter,
+        has_one = tweet,
+        seeds = [
+            RETWEET_SEED.as_bytes(),
+            retweeter.key().as_ref(),
+            tweet.key().as_ref(),
+        ],
+        bump = retweet.bump
+    )]
+    pub retweet: Account<'info, Retweet>,
+    #[account(mut)]
+    pub tweet: Account<'info, Tweet>,
+}
@@ -0,0 +1,62 @@
+//-------------------------------------------------------------------------------
+//
+// Unfollow
+//
+// - Only the follower can remove their follow
+// - Close the follow PDA and return its rent to the follower
+// - Decrement the follower's following count and the followee's followers count
+// - Emit an event for feed indexers
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::UnfollowEvent;
+use crate::states::*;
+
+pub fn unfollow(ctx: Context<UnfollowContext>) -> Result<()> {
+    let follower_profile = &mut ctx.accounts.follower_profile;
+    let followee_profile = &mut ctx.accounts.followee_profile;
+
+    require!(follower_profile.following > 0, TwitterError::MinFollowingReached);
+    require!(followee_profile.followers > 0, TwitterError::MinFollowersReached);
+    follower_profile.following -= 1;
+    followee_profile.followers -= 1;
+
+    emit!(UnfollowEvent {
+        follower: follower_profile.authority,
+        followee: followee_profile.authority,
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UnfollowContext<'info> {
+    #[account(mut)]
+    pub follower: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_bytes(), follower.key().as_ref()],
+        bump = follower_profile.bump
+    )]
+    pub follower_profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        seeds = [PROFILE_SEED.as_bytes(), followee_profile.authority.as_ref()],
+        bump = followee_profile.bump
+    )]
+    pub followee_profile: Account<'info, Profile>,
+    #[account(
+        mut,
+        close = follower,
+        has_one = follower,
+        seeds = [
+            FOLLOW_SEED.as_bytes(),
+            follower.key().as_ref(),
+            followee_profile.authority.as_ref()
+        ],
+        bump = follow.bump
+    )]
+    pub follow: Account<'info, Follow>,
+}
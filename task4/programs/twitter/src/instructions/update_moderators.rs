@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+//
+// Update Moderators
+//
+// - Only the admin can replace the moderator list
+// - Store up to MAX_MODERATORS moderator keys
+// - Emit a moderators updated event
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::errors::TwitterError;
+use crate::events::ModeratorsUpdatedEvent;
+use crate::states::*;
+
+pub fn update_moderators(ctx: Context<UpdateModeratorsContext>, moderators: Vec<Pubkey>) -> Result<()> {
+    require!(moderators.len() <= MAX_MODERATORS, TwitterError::TooManyModerators);
+
+    let config = &mut ctx.accounts.config;
+    config.moderators = moderators;
+
+    emit!(ModeratorsUpdatedEvent {
+        config: config.key(),
+        admin: config.admin,
+        moderators: config.moderators.clone(),
+    });
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateModeratorsContext<'info> {
+    pub admin: Signer<'info>,
+    #[account(
+        mut,
+        has_one = admin, // Ensure only the admin can change moderators
+        seeds = [CONFIG_SEED.as_bytes()],
+        bump = config.bump
+    )]
+    pub config: Account<'info, Config>,
+}
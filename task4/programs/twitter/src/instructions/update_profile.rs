@@ -0,0 +1,41 @@
+//-------------------------------------------------------------------------------
+//
+// Update Profile
+//
+// - Only the profile authority can update the profile
+// - Validate display name, bio and avatar URI against their maximum lengths
+// - The handle is managed separately through claim, change and release
+//
+//-------------------------------------------------------------------------------
+
+use anchor_lang::prelude::*;
+
+use crate::states::*;
+
+pub fn update_profile(
+    ctx: Context<UpdateProfileContext>,
+    display_name: String,
+    bio: String,
+    avatar_uri: String,
+) -> Result<()> {
+    Profile::validate(&display_name, &bio, &avatar_uri)?;
+
+    let profile = &mut ctx.accounts.profile;
+
+    profile.display_name = display_name;
+    profile.bio = bio;
+    profile.avatar_uri = avatar_uri;
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProfileContext<'info> {
+    pub authority: Signer<'info>,
+    #[account(
+        mut,
+        has_one = authority,
+        seeds = [PROFILE_SEED.as_bytes(), authority.key().as_ref()],
+        bump = profile.bump
+    )]
+    pub profile: Account<'info, Profile>,
+}
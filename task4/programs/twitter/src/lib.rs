@@ -6,7 +6,7 @@
 /// 
 /// This is a decentralized Twitter-like program built on Solana that allows users to:
 /// - Create tweets with topics and content
-/// - Add reactions (likes/dislikes) to tweets
+/// - Add reactions (likes, dislikes and emoji-style reactions) to tweets
 /// - Comment on tweets
 /// - Remove their own reactions and comments
 /// 
@@ -23,9 +23,19 @@
 /// - Imports
 /// 
 /// SEEDS:
-/// - Tweet: [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
+/// - Tweet: [TWEET_SEED.as_bytes(), tweet_authority.key().as_ref(), tweet_counter.next_tweet_id.to_le_bytes().as_ref()]
+/// - Legacy tweet: [topic.as_bytes(), TWEET_SEED.as_bytes(), tweet_authority.key().as_ref()]
+/// - TweetCounter: [TWEET_COUNTER_SEED.as_bytes(), tweet_authority.key().as_ref()]
+/// - Topic: [TOPIC_SEED.as_bytes(), topic_seed(&topic).as_ref()]
+/// - Profile: [PROFILE_SEED.as_bytes(), authority.key().as_ref()]
+/// - Handle: [HANDLE_SEED.as_bytes(), normalize_handle(&handle).as_bytes()]
+/// - Follow: [FOLLOW_SEED.as_bytes(), follower.key().as_ref(), followee.key().as_ref()]
+/// - Retweet: [RETWEET_SEED.as_bytes(), retweeter.key().as_ref(), tweet.key().as_ref()]
+/// - Config: [CONFIG_SEED.as_bytes()]
+/// - Report: [REPORT_SEED.as_bytes(), reporter.key().as_ref(), tweet.key().as_ref()]
 /// - TweetReaction: [TWEET_REACTION_SEED.as_bytes(), reaction_author.key().as_ref(), tweet.key().as_ref()]
-/// - Comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref()]
+/// - Comment and reply: [COMMENT_SEED.as_bytes(), tweet.key().as_ref(), tweet.next_comment_id.to_le_bytes().as_ref()]
+/// - Legacy comment: [COMMENT_SEED.as_bytes(), comment_author.key().as_ref(), {hash(comment.content.as_bytes()).to_bytes().as_ref()}, comment.parent_tweet.key().as_ref()]
 /// 
 /// GOOD LUCK!
 /// 
@@ -35,6 +45,7 @@ use crate::instructions::*;
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 pub mod instructions;
 pub mod states;
 
@@ -54,6 +65,9 @@ pub mod twitter {
     pub fn dislike_tweet(ctx: Context<AddReactionContext>) -> Result<()> {
         add_reaction(ctx, states::ReactionType::Dislike)
     }
+    pub fn react_tweet(ctx: Context<AddReactionContext>, reaction: states::ReactionType) -> Result<()> {
+        add_reaction(ctx, reaction)
+    }
     pub fn reaction_remove(ctx: Context<RemoveReactionContext>) -> Result<()> {
         remove_reaction(ctx)
     }
@@ -63,4 +77,97 @@ pub mod twitter {
     pub fn comment_remove(ctx: Context<RemoveCommentContext>) -> Result<()> {
         remove_comment(ctx)
     }
+    pub fn edit_tweet(ctx: Context<EditTweetContext>, content: String) -> Result<()> {
+        instructions::edit_tweet(ctx, content)
+    }
+    pub fn delete_tweet<'info>(
+        ctx: Context<'_, '_, 'info, 'info, DeleteTweetContext<'info>>,
+    ) -> Result<()> {
+        instructions::delete_tweet(ctx)
+    }
+    pub fn close_tweet_version(ctx: Context<CloseTweetVersionContext>) -> Result<()> {
+        instructions::close_tweet_version(ctx)
+    }
+    pub fn migrate_tweet(ctx: Context<MigrateTweetContext>) -> Result<()> {
+        instructions::migrate_tweet(ctx)
+    }
+    pub fn change_reaction(
+        ctx: Context<ChangeReactionContext>,
+        reaction: states::ReactionType,
+    ) -> Result<()> {
+        instructions::change_reaction(ctx, reaction)
+    }
+    pub fn comment_reply(ctx: Context<AddReplyContext>, comment_content: String) -> Result<()> {
+        add_reply(ctx, comment_content)
+    }
+    pub fn edit_comment(ctx: Context<EditCommentContext>, comment_content: String) -> Result<()> {
+        instructions::edit_comment(ctx, comment_content)
+    }
+    pub fn migrate_comment(ctx: Context<MigrateCommentContext>) -> Result<()> {
+        instructions::migrate_comment(ctx)
+    }
+    pub fn create_profile(
+        ctx: Context<CreateProfileContext>,
+        display_name: String,
+        bio: String,
+        avatar_uri: String,
+    ) -> Result<()> {
+        instructions::create_profile(ctx, display_name, bio, avatar_uri)
+    }
+    pub fn update_profile(
+        ctx: Context<UpdateProfileContext>,
+        display_name: String,
+        bio: String,
+        avatar_uri: String,
+    ) -> Result<()> {
+        instructions::update_profile(ctx, display_name, bio, avatar_uri)
+    }
+    pub fn claim_handle(ctx: Context<ClaimHandleContext>, handle: String) -> Result<()> {
+        instructions::claim_handle(ctx, handle)
+    }
+    pub fn change_handle(ctx: Context<ChangeHandleContext>, handle: String) -> Result<()> {
+        instructions::change_handle(ctx, handle)
+    }
+    pub fn release_handle(ctx: Context<ReleaseHandleContext>) -> Result<()> {
+        instructions::release_handle(ctx)
+    }
+    pub fn follow(ctx: Context<FollowContext>) -> Result<()> {
+        instructions::follow(ctx)
+    }
+    pub fn unfollow(ctx: Context<UnfollowContext>) -> Result<()> {
+        instructions::unfollow(ctx)
+    }
+    pub fn retweet(ctx: Context<RetweetContext>) -> Result<()> {
+        instructions::retweet(ctx)
+    }
+    pub fn undo_retweet(ctx: Context<UndoRetweetContext>) -> Result<()> {
+        instructions::undo_retweet(ctx)
+    }
+    pub fn quote_tweet(ctx: Context<QuoteTweetContext>, topic: String, content: String) -> Result<()> {
+        instructions::quote_tweet(ctx, topic, content)
+    }
+    pub fn tip_tweet(ctx: Context<TipTweetContext>, amount: u64) -> Result<()> {
+        instructions::tip_tweet(ctx, amount)
+    }
+    pub fn initialize_config(ctx: Context<InitializeConfigContext>, moderators: Vec<Pubkey>) -> Result<()> {
+        instructions::initialize_config(ctx, moderators)
+    }
+    pub fn update_moderators(ctx: Context<UpdateModeratorsContext>, moderators: Vec<Pubkey>) -> Result<()> {
+        instructions::update_moderators(ctx, moderators)
+    }
+    pub fn transfer_admin(ctx: Context<TransferAdminContext>, new_admin: Pubkey) -> Result<()> {
+        instructions::transfer_admin(ctx, new_admin)
+    }
+    pub fn report_tweet(ctx: Context<ReportTweetContext>) -> Result<()> {
+        instructions::report_tweet(ctx)
+    }
+    pub fn close_report(ctx: Context<CloseReportContext>) -> Result<()> {
+        instructions::close_report(ctx)
+    }
+    pub fn set_tweet_hidden(ctx: Context<SetTweetHiddenContext>, hidden: bool) -> Result<()> {
+        instructions::set_tweet_hidden(ctx, hidden)
+    }
+    pub fn set_comment_hidden(ctx: Context<SetCommentHiddenContext>, hidden: bool) -> Result<()> {
+        instructions::set_comment_hidden(ctx, hidden)
+    }
 }
@@ -1,17 +1,51 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::system_program::{transfer, Transfer};
+
+use crate::errors::TwitterError;
 
 pub const TOPIC_LENGTH: usize = 32;
 pub const CONTENT_LENGTH: usize = 500;
 pub const COMMENT_LENGTH: usize = 500;
+pub const DISPLAY_NAME_LENGTH: usize = 50;
+pub const BIO_LENGTH: usize = 160;
+pub const AVATAR_URI_LENGTH: usize = 200;
+pub const HANDLE_LENGTH: usize = 15;
+pub const MAX_MODERATORS: usize = 10;
 
 pub const TWEET_SEED: &str = "TWEET_SEED";
+pub const TWEET_COUNTER_SEED: &str = "TWEET_COUNTER_SEED";
+pub const TOPIC_SEED: &str = "TOPIC_SEED";
+pub const CONFIG_SEED: &str = "CONFIG_SEED";
+pub const REPORT_SEED: &str = "REPORT_SEED";
 pub const TWEET_REACTION_SEED: &str = "TWEET_REACTION_SEED";
 pub const COMMENT_SEED: &str = "COMMENT_SEED";
+pub const TWEET_VERSION_SEED: &str = "TWEET_VERSION_SEED";
+pub const PROFILE_SEED: &str = "PROFILE_SEED";
+pub const HANDLE_SEED: &str = "HANDLE_SEED";
+pub const FOLLOW_SEED: &str = "FOLLOW_SEED";
+pub const RETWEET_SEED: &str = "RETWEET_SEED";
+
+// Reaction types counted in `Tweet::reactions`; Like and Dislike keep the
+// `likes` and `dislikes` counters of the original layout
+pub const EXTRA_REACTION_TYPES: usize = 4;
 
-#[derive(AnchorDeserialize, AnchorSerialize, Clone, InitSpace)]
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum ReactionType {
     Like,
     Dislike,
+    Love,
+    Laugh,
+    Insightful,
+    Angry,
+}
+
+impl ReactionType {
+    /// Position of this reaction's counter in `Tweet::reactions`. Only valid
+    /// for the types after Like and Dislike.
+    fn index(self) -> usize {
+        self as usize - ReactionType::Love as usize
+    }
 }
 
 #[account]
@@ -25,6 +59,155 @@ pub struct Tweet {
     pub likes: u64,
     pub dislikes: u64,
     pub bump: u8,
+    pub version: u32,
+    pub edited_at: i64,
+    pub comment_count: u64,
+    // Counters for the reaction types after Like and Dislike, indexed by
+    // `ReactionType::index`
+    pub reactions: [u32; EXTRA_REACTION_TYPES],
+    // Sequence number the next comment on this tweet is seeded with
+    pub next_comment_id: u64,
+    pub retweets: u64,
+    // Original tweet when this tweet is a quote tweet
+    pub quoted_tweet: Option<Pubkey>,
+    // Position in the author's tweet sequence, part of the tweet seeds; None for
+    // tweets created under the legacy topic seeds
+    pub tweet_id: Option<u64>,
+    // Lamports tipped to the author through this tweet
+    pub total_tips: u64,
+    pub tip_count: u64,
+    pub report_count: u64,
+    // Set by moderators; hidden tweets accept no new reactions or comments
+    pub hidden: bool,
+    // Archived versions already closed ahead of deletion, oldest first
+    pub closed_versions: u32,
+}
+
+impl Tweet {
+    /// Checks the topic and content lengths and sets up a new tweet by the
+    /// counter's author, numbered by that counter and recorded on its topic.
+    pub fn init(
+        &mut self,
+        topic: String,
+        content: String,
+        quoted_tweet: Option<Pubkey>,
+        bump: u8,
+        tweet_counter: &mut TweetCounter,
+        topic_account: &mut Topic,
+    ) -> Result<()> {
+        require!(topic.len() <= TOPIC_LENGTH, TwitterError::TopicTooLong);
+        require!(content.len() <= CONTENT_LENGTH, TwitterError::ContentTooLong);
+
+        // Lowercasing can grow some characters, so check the normalized length too
+        let normalized_topic = normalize_topic(&topic);
+        require!(normalized_topic.len() <= TOPIC_LENGTH, TwitterError::TopicTooLong);
+
+        topic_account.topic = normalized_topic;
+        topic_account.record_tweet(Clock::get()?.unix_timestamp)?;
+
+        self.tweet_author = tweet_counter.author;
+        self.topic = topic;
+        self.content = content;
+        self.likes = 0;
+        self.dislikes = 0;
+        self.bump = bump;
+        self.version = 0;
+        self.edited_at = 0;
+        self.comment_count = 0;
+        self.reactions = [0; EXTRA_REACTION_TYPES];
+        self.next_comment_id = 0;
+        self.retweets = 0;
+        self.quoted_tweet = quoted_tweet;
+        self.tweet_id = Some(tweet_counter.next_id()?);
+        self.total_tips = 0;
+        self.tip_count = 0;
+        self.report_count = 0;
+        self.hidden = false;
+        self.closed_versions = 0;
+        Ok(())
+    }
+
+    /// Counts one more reaction of the given type.
+    pub fn add_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        match reaction {
+            ReactionType::Like => {
+                self.likes = self
+                    .likes
+                    .checked_add(1)
+                    .ok_or(TwitterError::MaxLikesReached)?;
+            }
+            ReactionType::Dislike => {
+                self.dislikes = self
+                    .dislikes
+                    .checked_add(1)
+                    .ok_or(TwitterError::MaxDislikesReached)?;
+            }
+            _ => {
+                let counter = &mut self.reactions[reaction.index()];
+                *counter = counter
+                    .checked_add(1)
+                    .ok_or(TwitterError::MaxReactionsReached)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Counts one reaction of the given type less.
+    pub fn remove_reaction(&mut self, reaction: ReactionType) -> Result<()> {
+        match reaction {
+            ReactionType::Like => {
+                self.likes = self
+                    .likes
+                    .checked_sub(1)
+                    .ok_or(TwitterError::MinLikesReached)?;
+            }
+            ReactionType::Dislike => {
+                self.dislikes = self
+                    .dislikes
+                    .checked_sub(1)
+                    .ok_or(TwitterError::MinDislikesReached)?;
+            }
+            _ => {
+                let counter = &mut self.reactions[reaction.index()];
+                *counter = counter
+                    .checked_sub(1)
+                    .ok_or(TwitterError::MinReactionsReached)?;
+            }
+        }
+        Ok(())
+    }
+
+    pub fn has_reactions(&self) -> bool {
+        self.likes > 0 || self.dislikes > 0 || self.reactions.iter().any(|count| *count > 0)
+    }
+
+    /// Whether `address` is this tweet's PDA under the scheme it was created
+    /// with: `[TWEET_SEED, tweet_author, tweet_id]`, or for legacy tweets
+    /// `[topic, TWEET_SEED, tweet_author]`.
+    pub fn is_address(&self, address: &Pubkey) -> bool {
+        let bump = [self.bump];
+        let derived = match self.tweet_id {
+            Some(tweet_id) => Pubkey::create_program_address(
+                &[
+                    TWEET_SEED.as_bytes(),
+                    self.tweet_author.as_ref(),
+                    tweet_id.to_le_bytes().as_ref(),
+                    &bump,
+                ],
+                &crate::ID,
+            ),
+            None => Pubkey::create_program_address(
+                &[
+                    self.topic.as_bytes(),
+                    TWEET_SEED.as_bytes(),
+                    self.tweet_author.as_ref(),
+                    &bump,
+                ],
+                &crate::ID,
+            ),
+        };
+        derived.is_ok_and(|derived| derived == *address)
+    }
 }
 
 #[account]
@@ -44,4 +227,248 @@ pub struct Comment {
     #[max_len(COMMENT_LENGTH)]
     pub content: String,
     pub bump: u8,
+    // Set on replies; `parent_tweet` then stays the tweet the thread belongs to
+    pub parent_comment: Option<Pubkey>,
+    pub reply_count: u64,
+    // None for comments created under the legacy content-hash seeds
+    pub comment_id: Option<u64>,
+    pub edited: bool,
+    pub edited_at: i64,
+    // Set by moderators; hidden comments accept no new replies
+    pub hidden: bool,
+}
+
+impl Comment {
+    /// Key the comment PDA is seeded with: the parent comment for replies,
+    /// the tweet otherwise.
+    pub fn seed_parent(&self) -> Pubkey {
+        self.parent_comment.unwrap_or(self.parent_tweet)
+    }
+
+    /// Whether `address` is this comment's PDA under the scheme it was created
+    /// with: `[COMMENT_SEED, parent_tweet, comment_id]`, or for legacy comments
+    /// `[COMMENT_SEED, comment_author, hash(content), seed_parent]`.
+    pub fn is_address(&self, address: &Pubkey) -> bool {
+        let bump = [self.bump];
+        let derived = match self.comment_id {
+            Some(comment_id) => Pubkey::create_program_address(
+                &[
+                    COMMENT_SEED.as_bytes(),
+                    self.parent_tweet.as_ref(),
+                    comment_id.to_le_bytes().as_ref(),
+                    &bump,
+                ],
+                &crate::ID,
+            ),
+            None => Pubkey::create_program_address(
+                &[
+                    COMMENT_SEED.as_bytes(),
+                    self.comment_author.as_ref(),
+                    hash(self.content.as_bytes()).to_bytes().as_ref(),
+                    self.seed_parent().as_ref(),
+                    &bump,
+                ],
+                &crate::ID,
+            ),
+        };
+        derived.is_ok_and(|derived| derived == *address)
+    }
+}
+
+/// Grows an account allocated for an older, shorter layout of `T` to `space`
+/// bytes, topping up its rent from `payer`. New fields only ever go after the
+/// ones of the original layout, so the zeroed tail decodes them as `None`, `0`
+/// or `false`.
+pub fn grow_legacy_account<'info, T: AccountDeserialize>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    space: usize,
+) -> Result<T> {
+    require!(account.data_len() < space, TwitterError::AccountUpToDate);
+
+    let mut data = account.try_borrow_data()?.to_vec();
+    data.resize(space, 0);
+    let decoded = T::try_deserialize(&mut data.as_slice())?;
+
+    let top_up = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: payer.clone(),
+                    to: account.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    account.resize(space)?;
+    Ok(decoded)
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TweetVersion {
+    pub tweet: Pubkey,
+    pub version: u32,
+    #[max_len(CONTENT_LENGTH)]
+    pub content: String,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Profile {
+    pub authority: Pubkey,
+    #[max_len(DISPLAY_NAME_LENGTH)]
+    pub display_name: String,
+    #[max_len(BIO_LENGTH)]
+    pub bio: String,
+    #[max_len(AVATAR_URI_LENGTH)]
+    pub avatar_uri: String,
+    // Handle as the user typed it; the registry is keyed by its normalized form
+    #[max_len(HANDLE_LENGTH)]
+    pub handle: Option<String>,
+    pub tweet_count: u64,
+    pub followers: u64,
+    pub following: u64,
+    pub bump: u8,
+}
+
+impl Profile {
+    /// Checks the user-provided profile fields against their maximum lengths.
+    pub fn validate(display_name: &str, bio: &str, avatar_uri: &str) -> Result<()> {
+        require!(display_name.len() <= DISPLAY_NAME_LENGTH, TwitterError::DisplayNameTooLong);
+        require!(bio.len() <= BIO_LENGTH, TwitterError::BioTooLong);
+        require!(avatar_uri.len() <= AVATAR_URI_LENGTH, TwitterError::AvatarUriTooLong);
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Handle {
+    pub authority: Pubkey,
+    // Normalized handle, the same bytes the PDA is seeded with
+    #[max_len(HANDLE_LENGTH)]
+    pub handle: String,
+    pub bump: u8,
+}
+
+/// Lowercase form of a handle, used to seed its registry PDA.
+pub fn normalize_handle(handle: &str) -> String {
+    handle.to_ascii_lowercase()
+}
+
+/// Handles are 1 to HANDLE_LENGTH ASCII letters, digits or underscores.
+pub fn validate_handle(handle: &str) -> Result<()> {
+    require!(
+        !handle.is_empty()
+            && handle.len() <= HANDLE_LENGTH
+            && handle.bytes().all(|c| c.is_ascii_alphanumeric() || c == b'_'),
+        TwitterError::InvalidHandle
+    );
+    Ok(())
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Follow {
+    pub follower: Pubkey,
+    pub followee: Pubkey,
+    pub followed_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Retweet {
+    pub retweeter: Pubkey,
+    pub tweet: Pubkey,
+    pub retweeted_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TweetCounter {
+    pub author: Pubkey,
+    // Sequence number the author's next tweet is seeded with, which is also
+    // the number of tweets the author has posted
+    pub next_tweet_id: u64,
+    pub bump: u8,
+}
+
+impl TweetCounter {
+    /// Hands out the next tweet id of the author and advances the sequence.
+    pub fn next_id(&mut self) -> Result<u64> {
+        let tweet_id = self.next_tweet_id;
+        self.next_tweet_id = tweet_id
+            .checked_add(1)
+            .ok_or(TwitterError::MaxTweetsReached)?;
+        Ok(tweet_id)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Topic {
+    // Normalized topic; the PDA is seeded with its hash to stay within seed limits
+    #[max_len(TOPIC_LENGTH)]
+    pub topic: String,
+    // Tweets posted on the topic, deleted tweets included
+    pub tweet_count: u64,
+    pub last_tweet_at: i64,
+    pub bump: u8,
+}
+
+impl Topic {
+    /// Counts a new tweet on the topic and records when it was posted.
+    pub fn record_tweet(&mut self, posted_at: i64) -> Result<()> {
+        self.tweet_count = self
+            .tweet_count
+            .checked_add(1)
+            .ok_or(TwitterError::MaxTweetsReached)?;
+        self.last_tweet_at = posted_at;
+        Ok(())
+    }
+}
+
+/// Trimmed, lowercase form of a topic, so "Solana" and " solana" share a Topic PDA.
+pub fn normalize_topic(topic: &str) -> String {
+    topic.trim().to_lowercase()
+}
+
+/// Seed of the Topic PDA for a topic, as typed by the author.
+pub fn topic_seed(topic: &str) -> [u8; 32] {
+    hash(normalize_topic(topic).as_bytes()).to_bytes()
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Config {
+    pub admin: Pubkey,
+    #[max_len(MAX_MODERATORS)]
+    pub moderators: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl Config {
+    /// The admin can always moderate, alongside the listed moderators.
+    pub fn is_moderator(&self, key: &Pubkey) -> bool {
+        self.admin == *key || self.moderators.contains(key)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Report {
+    pub reporter: Pubkey,
+    pub tweet: Pubkey,
+    pub reported_at: i64,
+    pub bump: u8,
 }
@@ -4,11 +4,24 @@ import { Twitter } from "../target/types/twitter";
 import { PublicKey } from '@solana/web3.js';
 import { assert } from "chai";
 import crypto from "crypto";
+import fs from "fs";
 
 
 const TWEET_SEED = "TWEET_SEED";
+const TWEET_COUNTER_SEED = "TWEET_COUNTER_SEED";
+const TOPIC_SEED = "TOPIC_SEED";
+const CONFIG_SEED = "CONFIG_SEED";
+const REPORT_SEED = "REPORT_SEED";
 const TWEET_REACTION = "TWEET_REACTION_SEED";
 const COMMENT_SEED = "COMMENT_SEED";
+const TWEET_VERSION_SEED = "TWEET_VERSION_SEED";
+const PROFILE_SEED = "PROFILE_SEED";
+const HANDLE_SEED = "HANDLE_SEED";
+const FOLLOW_SEED = "FOLLOW_SEED";
+const RETWEET_SEED = "RETWEET_SEED";
+
+// Positions of the love, laugh, insightful and angry counters in Tweet.reactions
+const LOVE_INDEX = 0;
 
 describe("twitter", () => {
   const provider = anchor.AnchorProvider.env();
@@ -51,12 +64,14 @@ describe("twitter", () => {
   describe("Initialize Tweet", async () => {
     it("Should successfully initialize a tweet with valid topic and content", async () => {
       await airdrop(provider.connection, bob.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_bob1, content_bob1).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_bob1, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -67,12 +82,14 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 32-byte topic (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_edge_case, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_edge_case, content_bob1).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_edge_case, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -83,12 +100,14 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with exactly 500-byte content (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EdgeContent", bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize("EdgeContent", content_edge_case).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress("EdgeContent", program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -99,12 +118,14 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with empty content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress("EmptyContent", bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize("EmptyContent", empty_content).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress("EmptyContent", program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -115,12 +136,14 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with single character topic and content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(single_char_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(single_char_topic, single_char_content).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(single_char_topic, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -131,12 +154,14 @@ describe("twitter", () => {
     });
 
     it("Should successfully initialize tweet with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(unicode_topic, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(unicode_topic, unicode_content).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(unicode_topic, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -150,12 +175,14 @@ describe("twitter", () => {
 
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob2, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
         await program.methods.initialize(topic_bob2, content_bob2).accounts(
           {
             tweetAuthority: bob.publicKey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
             tweet: tweet_pkey,
+            topicAccount: getTopicAddress(topic_bob2, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -169,12 +196,14 @@ describe("twitter", () => {
     it("Should fail to initialize tweet when content exceeds 500 bytes", async () => {
       let should_fail = "This Should Fail"
       try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob3, bob.publicKey, program.programId);
+        const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
         await program.methods.initialize(topic_bob3, content_bob3).accounts(
           {
             tweetAuthority: bob.publicKey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
             tweet: tweet_pkey,
+            topicAccount: getTopicAddress(topic_bob3, program.programId)[0],
             systemProgram: anchor.web3.SystemProgram.programId
           }
         ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -186,32 +215,41 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed with content longer than 500 bytes")
     });
 
-    it("Should fail to initialize duplicate tweet with same topic and author", async () => {
-      let should_fail = "This Should Fail"
-      try {
-        const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+    it("Should allow the same author to tweet twice on the same topic", async () => {
+      const [counter_pkey, counter_bump] = getTweetCounterAddress(bob.publicKey, program.programId);
+      const [first_pkey, first_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+      const tweet_id = (await program.account.tweetCounter.fetch(counter_pkey)).nextTweetId.toNumber();
 
-        await program.methods.initialize(topic_bob1, "Different content").accounts(
-          {
-            tweetAuthority: bob.publicKey,
-            tweet: tweet_pkey,
-            systemProgram: anchor.web3.SystemProgram.programId
-          }
-        ).signers([bob]).rpc({ commitment: "confirmed" })
-      } catch (error) {
-        should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error for duplicate tweet with same topic and author")
-      }
-      assert.strictEqual(should_fail, "Failed", "Tweet initialization should have failed when trying to create duplicate tweet with same topic and author")
+      await program.methods.initialize(topic_bob1, "Different content").accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweetCounter: counter_pkey,
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_bob1, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_bob1, "Different content", 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tweetId.toNumber(), tweet_id, `Tweet id should be ${tweet_id} but was ${tweetData.tweetId.toNumber()}`);
+      let counterData = await program.account.tweetCounter.fetch(counter_pkey);
+      assert.strictEqual(counterData.nextTweetId.toNumber(), tweet_id + 1, "Tweet counter should advance by one");
+      assert.notStrictEqual(tweet_pkey.toString(), first_pkey.toString(), "Second tweet on the topic should live at a new address");
     });
 
     it("Should successfully initialize second tweet with different topic for same author", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
 
       await program.methods.initialize(topic_bob4, content_bob4).accounts(
         {
           tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_bob4, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([bob]).rpc({ commitment: "confirmed" })
@@ -222,12 +260,14 @@ describe("twitter", () => {
 
     it("Should allow different users to create tweets with same topic", async () => {
       await airdrop(provider.connection, charlie.publicKey);
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
 
       await program.methods.initialize(topic_bob1, "Charlie's version").accounts(
         {
           tweetAuthority: charlie.publicKey,
+          tweetCounter: getTweetCounterAddress(charlie.publicKey, program.programId)[0],
           tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_bob1, program.programId)[0],
           systemProgram: anchor.web3.SystemProgram.programId
         }
       ).signers([charlie]).rpc({ commitment: "confirmed" })
@@ -242,7 +282,7 @@ describe("twitter", () => {
     it("Should successfully add like reaction to tweet", async () => {
       await airdrop(provider.connection, alice.publicKey);
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -262,7 +302,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'like' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -271,7 +311,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to like the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -296,7 +336,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike a tweet that is already liked", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -321,7 +361,7 @@ describe("twitter", () => {
     });
 
     it("Should successfully add dislike reaction to different tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -342,7 +382,7 @@ describe("twitter", () => {
     });
 
     it("Should correctly set reaction type to 'dislike' enum variant", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let reactionData = await program.account.reaction.fetch(reaction_pkey);
@@ -351,7 +391,7 @@ describe("twitter", () => {
 
     it("Should fail when attempting to dislike the same tweet twice", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -394,7 +434,7 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to react to the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -416,7 +456,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to react to non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("NonExistent", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = await findTweetAddress(program, bob.publicKey, "NonExistent");
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, fake_tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -439,7 +479,7 @@ describe("twitter", () => {
 
   describe("Remove Reaction", async () => {
     it("Should successfully remove existing reaction from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.reactionRemove().accounts(
@@ -456,7 +496,7 @@ describe("twitter", () => {
     });
 
     it("Should properly delete reaction account after removal", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail"
@@ -471,7 +511,7 @@ describe("twitter", () => {
 
     it("Should allow adding new reaction after previous reaction was removed", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
 
       await program.methods.likeTweet().accounts(
@@ -492,7 +532,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, charlie.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -512,7 +552,7 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's reaction", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
 
       let should_fail = "This should fail";
@@ -536,8 +576,8 @@ describe("twitter", () => {
   describe("Add Comment", async () => {
     it("Should fail when attempting to add comment exceeding length limit", async () => {
 
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice1, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       let should_fail = "This Should Fail"
       try {
@@ -559,8 +599,8 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with valid length to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(comment_alice2).accounts(
         {
@@ -576,9 +616,9 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with exactly 500 characters (boundary test)", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const max_comment = "C".repeat(500);
-      const [comment_pkey, comment_bump] = getCommentAddress(max_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(max_comment).accounts(
         {
@@ -595,9 +635,9 @@ describe("twitter", () => {
     });
 
     it("Should successfully add empty comment to tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const empty_comment = "";
-      const [comment_pkey, comment_bump] = getCommentAddress(empty_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(empty_comment).accounts(
         {
@@ -614,9 +654,9 @@ describe("twitter", () => {
     });
 
     it("Should successfully add comment with unicode characters and emojis", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(unicode_comment).accounts(
         {
@@ -633,9 +673,9 @@ describe("twitter", () => {
     });
 
     it("Should allow multiple users to comment on the same tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const charlie_comment = "Charlie's comment here";
-      const [comment_pkey, comment_bump] = getCommentAddress(charlie_comment, charlie.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(charlie_comment).accounts(
         {
@@ -652,9 +692,9 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to comment on non-existent tweet", async () => {
-      const [fake_tweet_pkey, fake_tweet_bump] = getTweetAddress("FakeTweet", bob.publicKey, program.programId);
+      const [fake_tweet_pkey, fake_tweet_bump] = await findTweetAddress(program, bob.publicKey, "FakeTweet");
       const test_comment = "This should fail";
-      const [comment_pkey, comment_bump] = getCommentAddress(test_comment, alice.publicKey, fake_tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = getCommentAddress(fake_tweet_pkey, 0, program.programId);
 
       let should_fail = "This should fail";
       try {
@@ -673,32 +713,34 @@ describe("twitter", () => {
       assert.strictEqual(should_fail, "Failed", "Should not be able to comment on a non-existent tweet");
     });
 
-    it("Should fail when attempting to create duplicate comment with same content", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+    it("Should allow posting a comment with the same content twice", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [first_pkey, first_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, comment_alice2);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
-      let should_fail = "This should fail";
-      try {
-        await program.methods.commentTweet(comment_alice2).accounts(
-          {
-            commentAuthor: alice.publicKey,
-            comment: comment_pkey,
-            tweet: tweet_pkey,
-            systemProgram: anchor.web3.SystemProgram.programId
-          }
-        ).signers([alice]).rpc({ commitment: "confirmed" })
-      } catch (error) {
-        should_fail = "Failed"
-        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when trying to create duplicate comment")
-      }
-      assert.strictEqual(should_fail, "Failed", "Should not be able to create duplicate comment with same content from same user");
+      await program.methods.commentTweet(comment_alice2).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      assert.notStrictEqual(comment_pkey.toString(), first_pkey.toString(), "Duplicate text should get its own comment account");
+      await checkComment(
+        program, comment_pkey, alice.publicKey, tweet_pkey, comment_alice2, comment_bump
+      )
+      await checkComment(
+        program, first_pkey, alice.publicKey, tweet_pkey, comment_alice2, first_bump
+      )
     });
   });
 
   describe("Remove Comment", async () => {
     it("Should successfully remove existing comment from tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, comment_alice2);
 
       await program.methods.commentRemove().accounts(
         {
@@ -719,9 +761,9 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove non-existent comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const fake_comment = "This comment doesn't exist";
-      const [comment_pkey, comment_bump] = getCommentAddress(fake_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, fake_comment);
 
       let should_fail = "This should fail";
       try {
@@ -739,9 +781,9 @@ describe("twitter", () => {
     });
 
     it("Should fail when attempting to remove another user's comment", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const unicode_comment = "Great tweet! 🎉✨ Love it! 💯";
-      const [comment_pkey, comment_bump] = getCommentAddress(unicode_comment, alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, unicode_comment);
 
       let should_fail = "This should fail";
       try {
@@ -759,8 +801,8 @@ describe("twitter", () => {
     });
 
     it("Should allow recreating comment with same content after deletion", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
-      const [comment_pkey, comment_bump] = getCommentAddress(comment_alice2, alice.publicKey, tweet_pkey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       // Recreate the same comment that was deleted
       await program.methods.commentTweet(comment_alice2).accounts(
@@ -780,7 +822,7 @@ describe("twitter", () => {
 
   describe("Edge Cases and Error Handling", async () => {
     it("Should allow tweet author to react to their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
 
       await program.methods.dislikeTweet().accounts(
@@ -799,9 +841,9 @@ describe("twitter", () => {
     });
 
     it("Should allow tweet author to comment on their own tweet", async () => {
-      const [tweet_pkey, tweet_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       const bob_comment = "Thanks for the likes everyone!";
-      const [comment_pkey, comment_bump] = getCommentAddress(bob_comment, bob.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
 
       await program.methods.commentTweet(bob_comment).accounts(
         {
@@ -819,56 +861,2287 @@ describe("twitter", () => {
 
     it("Should maintain correct final state across all tweets and reactions", async () => {
       // Bob's first tweet should have: 2 likes, 1 dislike
-      const [tweet1_pkey, tweet1_bump] = getTweetAddress(topic_bob1, bob.publicKey, program.programId);
+      const [tweet1_pkey, tweet1_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
       await checkTweet(program, tweet1_pkey, bob.publicKey, topic_bob1, content_bob1, 2, 1, tweet1_bump);
 
       // Bob's second tweet should have: 1 like, 0 dislikes
-      const [tweet2_pkey, tweet2_bump] = getTweetAddress(topic_bob4, bob.publicKey, program.programId);
+      const [tweet2_pkey, tweet2_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
       await checkTweet(program, tweet2_pkey, bob.publicKey, topic_bob4, content_bob4, 1, 0, tweet2_bump);
 
       // Charlie's tweet should have: 0 likes, 0 dislikes
-      const [tweet3_pkey, tweet3_bump] = getTweetAddress(topic_bob1, charlie.publicKey, program.programId);
+      const [tweet3_pkey, tweet3_bump] = await findTweetAddress(program, charlie.publicKey, topic_bob1);
       await checkTweet(program, tweet3_pkey, charlie.publicKey, topic_bob1, "Charlie's version", 0, 0, tweet3_bump);
     });
   });
 
-});
 
+  describe("Edit Tweet", async () => {
+    const topic_edit = "Editable";
+    const content_edit_v0 = "First draft with a typo";
+    const content_edit_v1 = "First draft without the typo";
 
-async function airdrop(connection: any, address: any, amount = 1000000000) {
-  await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
-}
+    it("Should successfully edit a tweet and keep the previous version", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+      const [version_pkey, version_bump] = getTweetVersionAddress(tweet_pkey, 0, program.programId);
 
-function getCommentAddress(comment_content: string, author: PublicKey, parent_tweet: PublicKey, programID: PublicKey) {
-  let hexString = crypto.createHash('sha256').update(comment_content, 'utf-8').digest('hex');
-  let content_seed = Uint8Array.from(Buffer.from(hexString, 'hex'));
+      await program.methods.initialize(topic_edit, content_edit_v0).accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_edit, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
 
+      const txSig = await program.methods.editTweet(content_edit_v1).accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetVersion: version_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
 
-  return PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
-      author.toBuffer(),
-      content_seed,
-      parent_tweet.toBuffer(),
-    ], programID);
-}
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit_v1, 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.version, 1, "Tweet version should be incremented to 1");
+      assert.isTrue(tweetData.editedAt.toNumber() > 0, "Tweet edit time should be recorded");
+
+      let versionData = await program.account.tweetVersion.fetch(version_pkey);
+      assert.strictEqual(versionData.tweet.toString(), tweet_pkey.toString(), "Version should point to the edited tweet");
+      assert.strictEqual(versionData.version, 0, "Version number should be 0");
+      assert.strictEqual(versionData.content, content_edit_v0, "Version should keep the previous content");
+      assert.strictEqual(versionData.bump, version_bump, "Version bump should be stored");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "tweetEditedEvent") {
+          eventEmitted = true;
+          const old_hash = crypto.createHash('sha256').update(content_edit_v0, 'utf-8').digest('hex');
+          assert.strictEqual(Buffer.from(event.data.oldContentHash).toString('hex'), old_hash, "Event should carry the hash of the previous content");
+          assert.strictEqual(event.data.version, 1, "Event version should be 1");
+        }
+      }
+      assert.isTrue(eventEmitted, "TweetEditedEvent should have been emitted");
+    });
 
-function getTweetAddress(topic: string, author: PublicKey, programID: PublicKey) {
-  return PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode(topic),
-      anchor.utils.bytes.utf8.encode(TWEET_SEED),
-      author.toBuffer()
-    ], programID);
-}
+    it("Should fail when a non-author attempts to edit a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_edit);
+      const [version_pkey, version_bump] = getTweetVersionAddress(tweet_pkey, 1, program.programId);
 
-function getReactionAddress(author: PublicKey, tweet: PublicKey, programID: PublicKey) {
-  return PublicKey.findProgramAddressSync(
-    [
-      anchor.utils.bytes.utf8.encode(TWEET_REACTION),
-      author.toBuffer(),
-      tweet.toBuffer(),
-    ], programID);
+      let should_fail = "This should fail";
+      try {
+        await program.methods.editTweet("Alice was here").accounts(
+          {
+            tweetAuthor: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetVersion: version_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "has_one") || SolanaError.contains(error.logs, "seeds"), "Expected has_one or seeds error when editing someone else's tweet")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to edit someone else's tweet");
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_edit, content_edit_v1, 0, 0, tweet_bump
+      )
+    });
+
+    it("Should fail to edit a tweet when content exceeds 500 bytes", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_edit);
+      const [version_pkey, version_bump] = getTweetVersionAddress(tweet_pkey, 1, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.editTweet(content_bob3).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetVersion: version_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ContentTooLong", "Expected 'ContentTooLong' error for content longer than 500 bytes");
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet edit should have failed with content longer than 500 bytes");
+    });
+  });
+
+
+  describe("Delete Tweet", async () => {
+    const topic_delete = "Short lived";
+    const comment_delete = "Going, going, gone";
+
+    it("Should fail to delete a tweet without providing its archived versions", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, "Editable");
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidTweetVersions", "Expected 'InvalidTweetVersions' error when versions are missing");
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet deletion should fail while archived versions are not provided");
+    });
+
+    it("Should successfully delete an edited tweet together with its versions", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, "Editable");
+      const [version_pkey, version_bump] = getTweetVersionAddress(tweet_pkey, 0, program.programId);
+
+      const txSig = await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).remainingAccounts([
+        { pubkey: version_pkey, isWritable: true, isSigner: false },
+      ]).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(tweet_pkey, "confirmed"), "Tweet account should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(version_pkey, "confirmed"), "Tweet version account should be closed");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "tweetDeletedEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.tweet.toString(), tweet_pkey.toString(), "Event should name the deleted tweet");
+        }
+      }
+      assert.isTrue(eventEmitted, "TweetDeletedEvent should have been emitted");
+    });
+
+    it("Should delete a tweet after closing its oldest versions separately", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+      const [version0_pkey, version0_bump] = getTweetVersionAddress(tweet_pkey, 0, program.programId);
+      const [version1_pkey, version1_bump] = getTweetVersionAddress(tweet_pkey, 1, program.programId);
+
+      await program.methods.initialize(topic_delete, "Draft one").accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_delete, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      for (const [content, version_pkey] of [["Draft two", version0_pkey], ["Draft three", version1_pkey]] as const) {
+        await program.methods.editTweet(content).accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetVersion: version_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      }
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.closeTweetVersion().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetVersion: version1_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "seeds"), "Expected seeds error when closing versions out of order")
+      }
+      assert.strictEqual(should_fail, "Failed", "Versions should only be closed oldest first");
+
+      await program.methods.closeTweetVersion().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+          tweetVersion: version0_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(version0_pkey, "confirmed"), "Oldest version account should be closed");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.closedVersions, 1, "Tweet should count one closed version");
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).remainingAccounts([
+        { pubkey: version1_pkey, isWritable: true, isSigner: false },
+      ]).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(tweet_pkey, "confirmed"), "Tweet account should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(version1_pkey, "confirmed"), "Remaining version account should be closed");
+    });
+
+    it("Should fail to delete a tweet that still has reactions", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasReactions", "Expected 'TweetHasReactions' error for a tweet with reactions");
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet deletion should fail while reactions exist");
+    });
+
+    it("Should fail when a non-author attempts to delete a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: alice.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "has_one") || SolanaError.contains(error.logs, "seeds"), "Expected has_one or seeds error when deleting someone else's tweet")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to delete someone else's tweet");
+    });
+
+    it("Should delete a tweet once its comments are removed", async () => {
+      await airdrop(provider.connection, charlie.publicKey);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      await program.methods.initialize(topic_delete, "Delete me later").accounts(
+        {
+          tweetAuthority: charlie.publicKey,
+          tweetCounter: getTweetCounterAddress(charlie.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_delete, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await program.methods.commentTweet(comment_delete).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "1", "Tweet comment count should be 1");
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: charlie.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasComments", "Expected 'TweetHasComments' error for a tweet with comments");
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet deletion should fail while comments exist");
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toString(), "0", "Tweet comment count should be back to 0");
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: charlie.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(tweet_pkey, "confirmed"), "Tweet account should be closed");
+    });
+  });
+
+
+  describe("Change Reaction", async () => {
+    it("Should successfully switch a like to a dislike in place", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.changeReaction({ dislike: {} }).accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      // Alice moved from like to dislike: 1 like (Charlie), 2 dislikes (Alice, Bob)
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_bob1, content_bob1, 1, 2, tweet_bump
+      )
+      await checkReaction(
+        program, reaction_pkey, alice.publicKey, tweet_pkey, reaction_bump
+      )
+      let reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { dislike: {} }, "Reaction type should be changed to 'dislike' enum variant");
+    });
+
+    it("Should fail when changing a reaction to its current type", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.changeReaction({ dislike: {} }).accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "SameReaction", "Expected 'SameReaction' error when the reaction type does not change");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to change a reaction to the same type");
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_bob1, content_bob1, 1, 2, tweet_bump
+      )
+    });
+
+    it("Should fail when attempting to change another user's reaction", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.changeReaction({ like: {} }).accounts(
+          {
+            reactionAuthor: charlie.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(error.message.includes("constraint") || error.message.includes("seeds"), "Expected constraint or seeds error when changing someone else's reaction")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to change someone else's reaction");
+    });
+
+    it("Should successfully switch a dislike back to a like", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.changeReaction({ like: {} }).accounts(
+        {
+          reactionAuthor: alice.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_bob1, content_bob1, 2, 1, tweet_bump
+      )
+    });
+  });
+
+
+  describe("Extended Reactions", async () => {
+    it("Should successfully add a love reaction to its own counter", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactTweet({ love: {} }).accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      // Love reactions are counted separately from likes and dislikes
+      await checkTweet(
+        program, tweet_pkey, bob.publicKey, topic_bob4, content_bob4, 1, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reactions[LOVE_INDEX], 1, "Tweet love counter should be 1");
+
+      let reactionData = await program.account.reaction.fetch(reaction_pkey);
+      assert.deepEqual(reactionData.reaction, { love: {} }, "Reaction type should be set to 'love' enum variant");
+    });
+
+    it("Should successfully change a love reaction to laugh", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.changeReaction({ laugh: {} }).accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.likes.toNumber(), 1, "Likes should be unchanged");
+      assert.deepEqual(tweetData.reactions, [0, 1, 0, 0], "Counts should move from love to laugh");
+    });
+
+    it("Should successfully remove an extended reaction", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob4);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(bob.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.reactionRemove().accounts(
+        {
+          reactionAuthor: bob.publicKey,
+          tweetReaction: reaction_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.likes.toNumber(), 1, "The remaining like should still be counted");
+      assert.deepEqual(tweetData.reactions, [0, 0, 0, 0], "No extra reactions should be counted");
+    });
+  });
+
+
+  describe("Threaded Replies", async () => {
+    const bob_comment = "Thanks for the likes everyone!";
+    const reply_charlie = "You're welcome Bob";
+    const reply_alice = "Replying to the reply";
+
+    it("Should successfully reply to a comment", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, bob.publicKey, bob_comment);
+      // Replies take the next comment id of the tweet, like top-level comments
+      const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      const comment_count_before = (await program.account.tweet.fetch(tweet_pkey)).commentCount.toNumber();
+
+      await program.methods.commentReply(reply_charlie).accounts(
+        {
+          commentAuthor: charlie.publicKey,
+          comment: reply_pkey,
+          parentComment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, reply_pkey, charlie.publicKey, tweet_pkey, reply_charlie, reply_bump
+      )
+      let replyData = await program.account.comment.fetch(reply_pkey);
+      assert.strictEqual(replyData.parentComment.toString(), comment_pkey.toString(), "Reply should reference its parent comment");
+
+      let parentData = await program.account.comment.fetch(comment_pkey);
+      assert.strictEqual(parentData.replyCount.toString(), "1", "Parent comment reply count should be 1");
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toNumber(), comment_count_before + 1, "Replies should count as comments on the tweet");
+    });
+
+    it("Should successfully reply to a reply", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, bob.publicKey, bob_comment);
+      const [reply_pkey, reply_bump] = await findCommentAddress(program, tweet_pkey, charlie.publicKey, reply_charlie);
+      const [nested_pkey, nested_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      await program.methods.commentReply(reply_alice).accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: nested_pkey,
+          parentComment: reply_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, nested_pkey, alice.publicKey, tweet_pkey, reply_alice, nested_bump
+      )
+      let replyData = await program.account.comment.fetch(reply_pkey);
+      assert.strictEqual(replyData.replyCount.toString(), "1", "Reply should count its own reply");
+    });
+
+    it("Should fail when attempting to remove a comment that has replies", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, bob.publicKey, bob_comment);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentRemove().accounts(
+          {
+            commentAuthor: bob.publicKey,
+            comment: comment_pkey,
+            parentTweet: tweet_pkey,
+            parentComment: null,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CommentHasReplies", "Expected 'CommentHasReplies' error for a comment with replies");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to remove a comment that still has replies");
+    });
+
+    it("Should fail when removing a reply without its parent comment", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, bob.publicKey, bob_comment);
+      const [reply_pkey, reply_bump] = await findCommentAddress(program, tweet_pkey, charlie.publicKey, reply_charlie);
+      const [nested_pkey, nested_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, reply_alice);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentRemove().accounts(
+          {
+            commentAuthor: alice.publicKey,
+            comment: nested_pkey,
+            parentTweet: tweet_pkey,
+            parentComment: null,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidParentComment", "Expected 'InvalidParentComment' error when the parent comment is missing");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to remove a reply without its parent comment");
+    });
+
+    it("Should successfully remove a reply and update its parent", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [comment_pkey, comment_bump] = await findCommentAddress(program, tweet_pkey, bob.publicKey, bob_comment);
+      const [reply_pkey, reply_bump] = await findCommentAddress(program, tweet_pkey, charlie.publicKey, reply_charlie);
+      const [nested_pkey, nested_bump] = await findCommentAddress(program, tweet_pkey, alice.publicKey, reply_alice);
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: nested_pkey,
+          parentTweet: tweet_pkey,
+          parentComment: reply_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(nested_pkey, "confirmed"), "Reply account should be closed");
+      let replyData = await program.account.comment.fetch(reply_pkey);
+      assert.strictEqual(replyData.replyCount.toString(), "0", "Parent reply count should be back to 0");
+    });
+  });
+
+
+  describe("Sequential Comments", async () => {
+    const topic_paged = "Paged comments";
+
+    it("Should number comments per tweet and keep ids after removal", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      await program.methods.initialize(topic_paged, "Comment away").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          tweetCounter: getTweetCounterAddress(alice.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_paged, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      for (let comment_id = 0; comment_id < 2; comment_id++) {
+        const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, comment_id, program.programId);
+        await program.methods.commentTweet("Same text every time").accounts(
+          {
+            commentAuthor: charlie.publicKey,
+            comment: comment_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+        let commentData = await program.account.comment.fetch(comment_pkey);
+        assert.strictEqual(commentData.commentId.toNumber(), comment_id, `Comment id should be ${comment_id}`);
+      }
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toNumber(), 2, "Tweet comment count should be 2");
+      assert.strictEqual(tweetData.nextCommentId.toNumber(), 2, "Tweet next comment id should be 2");
+
+      const [first_pkey, first_bump] = getCommentAddress(tweet_pkey, 0, program.programId);
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: charlie.publicKey,
+          comment: first_pkey,
+          parentTweet: tweet_pkey,
+          parentComment: null,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toNumber(), 1, "Tweet comment count should drop to 1");
+      assert.strictEqual(tweetData.nextCommentId.toNumber(), 2, "Comment ids should never be reused");
+    });
+  });
+
+
+  describe("Edit Comment", async () => {
+    const topic_paged = "Paged comments";
+    const comment_edited = "Changed my mind";
+
+    it("Should successfully edit a comment", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, alice.publicKey, topic_paged);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      const txSig = await program.methods.editComment(comment_edited).accounts(
+        {
+          commentAuthor: charlie.publicKey,
+          comment: comment_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkComment(
+        program, comment_pkey, charlie.publicKey, tweet_pkey, comment_edited, comment_bump
+      )
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      assert.isTrue(commentData.edited, "Comment should be flagged as edited");
+      assert.isTrue(commentData.editedAt.toNumber() > 0, "Comment edit time should be recorded");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "commentEditedEvent") {
+          eventEmitted = true;
+          const old_hash = crypto.createHash('sha256').update("Same text every time", 'utf-8').digest('hex');
+          assert.strictEqual(Buffer.from(event.data.oldContentHash).toString('hex'), old_hash, "Event should carry the hash of the previous content");
+          assert.strictEqual(event.data.comment.toString(), comment_pkey.toString(), "Event should name the edited comment");
+        }
+      }
+      assert.isTrue(eventEmitted, "CommentEditedEvent should have been emitted");
+    });
+
+    it("Should fail when a non-author attempts to edit a comment", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, alice.publicKey, topic_paged);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.editComment("Alice rewrote this").accounts(
+          {
+            commentAuthor: alice.publicKey,
+            comment: comment_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "has_one"), "Expected has_one error when editing someone else's comment")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to edit someone else's comment");
+      await checkComment(
+        program, comment_pkey, charlie.publicKey, tweet_pkey, comment_edited, comment_bump
+      )
+    });
+
+    it("Should fail to edit a comment when content exceeds 500 bytes", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, alice.publicKey, topic_paged);
+      const [comment_pkey, comment_bump] = getCommentAddress(tweet_pkey, 1, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.editComment(comment_alice1).accounts(
+          {
+            commentAuthor: charlie.publicKey,
+            comment: comment_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CommentTooLong", "Expected 'CommentTooLong' error for comment longer than 500 bytes");
+      }
+      assert.strictEqual(should_fail, "Failed", "Comment edit should have failed with comment longer than 500 bytes");
+    });
+  });
+
+
+  describe("Profiles and Handles", async () => {
+    const topic_profile = "Profile tweet";
+
+    it("Should successfully create a profile", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      await program.methods.createProfile("Alice", "Not a fan of Bob", "https://example.com/alice.png").accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.authority.toString(), alice.publicKey.toString(), `Profile authority should be ${alice.publicKey.toString()} but was ${profileData.authority.toString()}`);
+      assert.strictEqual(profileData.displayName, "Alice", "Display name should be stored");
+      assert.isNull(profileData.handle, "Profile should start without a handle");
+      assert.strictEqual(profileData.tweetCount.toString(), "0", "Tweet count should start at zero");
+      assert.strictEqual(profileData.bump, profile_bump, `Profile bump should be ${profile_bump} but was ${profileData.bump}`);
+    });
+
+    it("Should fail to create a profile when the bio exceeds 160 bytes", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.createProfile("Charlie", "B".repeat(161), "").accounts(
+          {
+            authority: charlie.publicKey,
+            profile: profile_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "BioTooLong", "Expected 'BioTooLong' error for bio longer than 160 bytes");
+      }
+      assert.strictEqual(should_fail, "Failed", "Profile creation should have failed with bio longer than 160 bytes");
+    });
+
+    it("Should successfully update a profile", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+
+      await program.methods.updateProfile("Alice W.", "Still not a fan of Bob", "").accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.displayName, "Alice W.", "Display name should be updated");
+      assert.strictEqual(profileData.bio, "Still not a fan of Bob", "Bio should be updated");
+      assert.strictEqual(profileData.avatarUri, "", "Avatar URI should be updated");
+    });
+
+    it("Should successfully claim a handle", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("Alice_W", program.programId);
+
+      await program.methods.claimHandle("Alice_W").accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+          handleAccount: handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.handle, "Alice_W", "Profile should keep the handle as typed");
+      let handleData = await program.account.handle.fetch(handle_pkey);
+      assert.strictEqual(handleData.handle, "alice_w", "Registry should store the normalized handle");
+      assert.strictEqual(handleData.authority.toString(), alice.publicKey.toString(), "Handle should belong to Alice");
+    });
+
+    it("Should fail to claim a handle that differs only in casing", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("ALICE_w", program.programId);
+
+      await program.methods.createProfile("Charlie", "", "").accounts(
+        {
+          authority: charlie.publicKey,
+          profile: profile_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.claimHandle("ALICE_w").accounts(
+          {
+            authority: charlie.publicKey,
+            profile: profile_pkey,
+            handleAccount: handle_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error for a taken handle")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to claim a taken handle");
+    });
+
+    it("Should fail to claim a handle with invalid characters", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("charlie!", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.claimHandle("charlie!").accounts(
+          {
+            authority: charlie.publicKey,
+            profile: profile_pkey,
+            handleAccount: handle_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidHandle", "Expected 'InvalidHandle' error for a handle with invalid characters");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to claim a handle with invalid characters");
+    });
+
+    it("Should increment the profile tweet count when tweeting with a profile", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      await program.methods.initialize(topic_profile, "Now with a profile").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          tweetCounter: getTweetCounterAddress(alice.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_profile, program.programId)[0],
+          profile: profile_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.tweetCount.toString(), "1", "Tweet count should be incremented");
+    });
+
+    it("Should fail to tweet with someone else's profile", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.initialize(topic_profile, "Borrowing Alice's profile").accounts(
+          {
+            tweetAuthority: bob.publicKey,
+            tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+            tweet: tweet_pkey,
+            topicAccount: getTopicAddress(topic_profile, program.programId)[0],
+            profile: profile_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidProfile", "Expected 'InvalidProfile' error when using someone else's profile");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tweet with someone else's profile");
+    });
+
+    it("Should successfully change a handle and free the old one", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [old_handle_pkey, old_handle_bump] = getHandleAddress("Alice_W", program.programId);
+      const [new_handle_pkey, new_handle_bump] = getHandleAddress("alice", program.programId);
+
+      await program.methods.changeHandle("alice").accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+          oldHandleAccount: old_handle_pkey,
+          newHandleAccount: new_handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.handle, "alice", "Profile should hold the new handle");
+      let oldHandleInfo = await provider.connection.getAccountInfo(old_handle_pkey);
+      assert.isNull(oldHandleInfo, "Old handle account should be closed");
+
+      const [charlie_profile_pkey, charlie_profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      await program.methods.claimHandle("alice_w").accounts(
+        {
+          authority: charlie.publicKey,
+          profile: charlie_profile_pkey,
+          handleAccount: old_handle_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let handleData = await program.account.handle.fetch(old_handle_pkey);
+      assert.strictEqual(handleData.authority.toString(), charlie.publicKey.toString(), "Freed handle should be claimable by Charlie");
+    });
+
+    it("Should change only the casing of a handle in place", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("Alice", program.programId);
+
+      await program.methods.changeHandle("Alice").accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+          oldHandleAccount: handle_pkey,
+          newHandleAccount: null,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.strictEqual(profileData.handle, "Alice", "Profile should show the new casing");
+      let handleData = await program.account.handle.fetch(handle_pkey);
+      assert.strictEqual(handleData.authority.toString(), alice.publicKey.toString(), "Registry entry should still belong to Alice");
+      assert.strictEqual(handleData.handle, "alice", "Registry entry should keep the normalized handle");
+    });
+
+    it("Should fail to change to a different handle without its registry account", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice", program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.changeHandle("alice_b").accounts(
+          {
+            authority: alice.publicKey,
+            profile: profile_pkey,
+            oldHandleAccount: handle_pkey,
+            newHandleAccount: null,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "HandleAccountRequired", "Expected 'HandleAccountRequired' error without the new registry account");
+      }
+      assert.strictEqual(should_fail, "Failed", "Changing to another handle should require its registry account");
+    });
+
+    it("Should successfully release a handle", async () => {
+      const [profile_pkey, profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [handle_pkey, handle_bump] = getHandleAddress("alice", program.programId);
+
+      await program.methods.releaseHandle().accounts(
+        {
+          authority: alice.publicKey,
+          profile: profile_pkey,
+          handleAccount: handle_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let profileData = await program.account.profile.fetch(profile_pkey);
+      assert.isNull(profileData.handle, "Profile handle should be cleared");
+      let handleInfo = await provider.connection.getAccountInfo(handle_pkey);
+      assert.isNull(handleInfo, "Handle account should be closed");
+    });
+  });
+
+
+  describe("Follow", async () => {
+    it("Should successfully follow another user", async () => {
+      const [charlie_profile_pkey, charlie_profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(charlie.publicKey, alice.publicKey, program.programId);
+
+      const txSig = await program.methods.follow().accounts(
+        {
+          follower: charlie.publicKey,
+          followerProfile: charlie_profile_pkey,
+          followeeProfile: alice_profile_pkey,
+          follow: follow_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let followData = await program.account.follow.fetch(follow_pkey);
+      assert.strictEqual(followData.follower.toString(), charlie.publicKey.toString(), "Follower should be Charlie");
+      assert.strictEqual(followData.followee.toString(), alice.publicKey.toString(), "Followee should be Alice");
+      assert.strictEqual(followData.bump, follow_bump, `Follow bump should be ${follow_bump} but was ${followData.bump}`);
+
+      let charlieProfile = await program.account.profile.fetch(charlie_profile_pkey);
+      let aliceProfile = await program.account.profile.fetch(alice_profile_pkey);
+      assert.strictEqual(charlieProfile.following.toString(), "1", "Charlie should follow one user");
+      assert.strictEqual(aliceProfile.followers.toString(), "1", "Alice should have one follower");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "followEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.follower.toString(), charlie.publicKey.toString(), "Event should name the follower");
+          assert.strictEqual(event.data.followee.toString(), alice.publicKey.toString(), "Event should name the followee");
+        }
+      }
+      assert.isTrue(eventEmitted, "FollowEvent should have been emitted");
+    });
+
+    it("Should fail to follow the same user twice", async () => {
+      const [charlie_profile_pkey, charlie_profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(charlie.publicKey, alice.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.follow().accounts(
+          {
+            follower: charlie.publicKey,
+            followerProfile: charlie_profile_pkey,
+            followeeProfile: alice_profile_pkey,
+            follow: follow_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when following twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to follow the same user twice");
+      let aliceProfile = await program.account.profile.fetch(alice_profile_pkey);
+      assert.strictEqual(aliceProfile.followers.toString(), "1", "Alice should still have one follower");
+    });
+
+    it("Should fail to follow yourself", async () => {
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(alice.publicKey, alice.publicKey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.follow().accounts(
+          {
+            follower: alice.publicKey,
+            followerProfile: alice_profile_pkey,
+            followeeProfile: alice_profile_pkey,
+            follow: follow_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotFollowSelf", "Expected 'CannotFollowSelf' error when following yourself");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to follow yourself");
+    });
+
+    it("Should successfully unfollow a user", async () => {
+      const [charlie_profile_pkey, charlie_profile_bump] = getProfileAddress(charlie.publicKey, program.programId);
+      const [alice_profile_pkey, alice_profile_bump] = getProfileAddress(alice.publicKey, program.programId);
+      const [follow_pkey, follow_bump] = getFollowAddress(charlie.publicKey, alice.publicKey, program.programId);
+
+      await program.methods.unfollow().accounts(
+        {
+          follower: charlie.publicKey,
+          followerProfile: charlie_profile_pkey,
+          followeeProfile: alice_profile_pkey,
+          follow: follow_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let followInfo = await provider.connection.getAccountInfo(follow_pkey);
+      assert.isNull(followInfo, "Follow account should be closed");
+      let charlieProfile = await program.account.profile.fetch(charlie_profile_pkey);
+      let aliceProfile = await program.account.profile.fetch(alice_profile_pkey);
+      assert.strictEqual(charlieProfile.following.toString(), "0", "Charlie should follow nobody");
+      assert.strictEqual(aliceProfile.followers.toString(), "0", "Alice should have no followers");
+    });
+  });
+
+
+  describe("Retweets and Quote Tweets", async () => {
+    const topic_quote = "Quoting Bob";
+    const content_quote = "Look what Bob said";
+
+    it("Should successfully retweet a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.retweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          retweet: retweet_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let retweetData = await program.account.retweet.fetch(retweet_pkey);
+      assert.strictEqual(retweetData.retweeter.toString(), charlie.publicKey.toString(), "Retweeter should be Charlie");
+      assert.strictEqual(retweetData.tweet.toString(), tweet_pkey.toString(), "Retweet should point at the original tweet");
+      assert.strictEqual(retweetData.bump, retweet_bump, `Retweet bump should be ${retweet_bump} but was ${retweetData.bump}`);
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.retweets.toString(), "1", "Original tweet should have one retweet");
+    });
+
+    it("Should fail to retweet the same tweet twice", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.retweet().accounts(
+          {
+            retweeter: charlie.publicKey,
+            retweet: retweet_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when retweeting twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to retweet the same tweet twice");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.retweets.toString(), "1", "Retweet counter should be unchanged");
+    });
+
+    it("Should fail when a non-retweeter attempts to undo a retweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.undoRetweet().accounts(
+          {
+            retweeter: alice.publicKey,
+            retweet: retweet_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to undo someone else's retweet");
+    });
+
+    it("Should successfully undo a retweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.undoRetweet().accounts(
+        {
+          retweeter: charlie.publicKey,
+          retweet: retweet_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let retweetInfo = await provider.connection.getAccountInfo(retweet_pkey);
+      assert.isNull(retweetInfo, "Retweet account should be closed");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.retweets.toString(), "0", "Original tweet should have no retweets");
+    });
+
+    it("Should successfully quote a tweet", async () => {
+      const [original_pkey, original_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, charlie.publicKey);
+      const [counter_pkey, counter_bump] = getTweetCounterAddress(charlie.publicKey, program.programId);
+      const tweet_count = (await program.account.tweetCounter.fetch(counter_pkey)).nextTweetId.toNumber();
+
+      await program.methods.quoteTweet(topic_quote, content_quote).accounts(
+        {
+          tweetAuthority: charlie.publicKey,
+          tweetCounter: counter_pkey,
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_quote, program.programId)[0],
+          quotedTweet: original_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, charlie.publicKey, topic_quote, content_quote, 0, 0, tweet_bump
+      )
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.quotedTweet.toString(), original_pkey.toString(), "Quote tweet should reference the original");
+      let counterData = await program.account.tweetCounter.fetch(counter_pkey);
+      assert.strictEqual(counterData.nextTweetId.toNumber(), tweet_count + 1, "Quote tweet should count towards the author's tweets");
+    });
+  });
+
+
+  describe("Topics", async () => {
+    it("Should record tweets on the normalized topic", async () => {
+      const [topic_pkey, topic_bump] = getTopicAddress(topic_bob1, program.programId);
+      const topicBefore = await program.account.topic.fetch(topic_pkey);
+      assert.strictEqual(topicBefore.topic, topic_bob1.toLowerCase(), "Topic should store the normalized topic");
+      assert.strictEqual(topicBefore.bump, topic_bump, `Topic bump should be ${topic_bump} but was ${topicBefore.bump}`);
+
+      const topic_variant = "  HELLO there ";
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      await program.methods.initialize(topic_variant, "Same topic, different spelling").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          tweetCounter: getTweetCounterAddress(alice.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_variant, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const topicAfter = await program.account.topic.fetch(topic_pkey);
+      assert.strictEqual(topicAfter.tweetCount.toNumber(), topicBefore.tweetCount.toNumber() + 1, "Topic tweet count should be incremented");
+      assert.isTrue(topicAfter.lastTweetAt.toNumber() >= topicBefore.lastTweetAt.toNumber(), "Topic last tweet time should be updated");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.topic, topic_variant, "Tweet should keep the topic as typed");
+    });
+
+    it("Should create a topic on its first tweet", async () => {
+      const topic_new = "Brand new topic";
+      const [topic_pkey, topic_bump] = getTopicAddress(topic_new, program.programId);
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, alice.publicKey);
+
+      let topicInfo = await provider.connection.getAccountInfo(topic_pkey);
+      assert.isNull(topicInfo, "Topic should not exist before its first tweet");
+
+      await program.methods.initialize(topic_new, "First!").accounts(
+        {
+          tweetAuthority: alice.publicKey,
+          tweetCounter: getTweetCounterAddress(alice.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: topic_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const topicData = await program.account.topic.fetch(topic_pkey);
+      assert.strictEqual(topicData.topic, "brand new topic", "Topic should store the normalized topic");
+      assert.strictEqual(topicData.tweetCount.toNumber(), 1, "New topic should count one tweet");
+      assert.isTrue(topicData.lastTweetAt.toNumber() > 0, "Topic last tweet time should be recorded");
+    });
+  });
+
+
+  describe("Tip Tweet", async () => {
+    const tip_amount = 10_000_000;
+
+    it("Should successfully tip the author of a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+      const authorBalanceBefore = await provider.connection.getBalance(bob.publicKey);
+
+      const txSig = await program.methods.tipTweet(new anchor.BN(tip_amount)).accounts(
+        {
+          tipper: alice.publicKey,
+          tweet: tweet_pkey,
+          tweetAuthor: bob.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      const authorBalanceAfter = await provider.connection.getBalance(bob.publicKey);
+      assert.strictEqual(authorBalanceAfter - authorBalanceBefore, tip_amount, "Author should receive the full tip");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.totalTips.toNumber(), tip_amount, "Tweet total tips should include the tip");
+      assert.strictEqual(tweetData.tipCount.toNumber(), 1, "Tweet tip count should be incremented");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "tipEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.tipper.toString(), alice.publicKey.toString(), "Event should name the tipper");
+          assert.strictEqual(event.data.tweetAuthor.toString(), bob.publicKey.toString(), "Event should name the author");
+          assert.strictEqual(event.data.amount.toNumber(), tip_amount, "Event should carry the tip amount");
+        }
+      }
+      assert.isTrue(eventEmitted, "TipEvent should have been emitted");
+    });
+
+    it("Should fail to tip an account that is not the tweet author", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.tipTweet(new anchor.BN(tip_amount)).accounts(
+          {
+            tipper: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: charlie.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "has_one"), "Expected has_one error when tipping someone other than the author")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to redirect a tip away from the author");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.tipCount.toNumber(), 1, "Tweet tip count should be unchanged");
+    });
+
+    it("Should fail to tip zero lamports", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.tipTweet(new anchor.BN(0)).accounts(
+          {
+            tipper: alice.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: bob.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "InvalidTipAmount", "Expected 'InvalidTipAmount' error for a zero tip");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tip zero lamports");
+    });
+
+    it("Should fail to tip your own tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_bob1);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.tipTweet(new anchor.BN(1000)).accounts(
+          {
+            tipper: bob.publicKey,
+            tweet: tweet_pkey,
+            tweetAuthor: bob.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotTipSelf", "Expected 'CannotTipSelf' error when tipping your own tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to tip your own tweet");
+    });
+  });
+
+
+  describe("Moderation", async () => {
+    const topic_moderated = "Moderated";
+    const topic_reported = "Reported";
+
+    it("Should fail to initialize the config without the upgrade authority", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.initializeConfig([bob.publicKey]).accounts(
+          {
+            admin: bob.publicKey,
+            program: program.programId,
+            programData: getProgramDataAddress(program.programId),
+            config: config_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotUpgradeAuthority", "Expected 'NotUpgradeAuthority' error when Bob initializes the config");
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the upgrade authority should be able to initialize the config");
+    });
+
+    it("Should successfully initialize the config with moderators", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+
+      await program.methods.initializeConfig([charlie.publicKey]).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          program: program.programId,
+          programData: getProgramDataAddress(program.programId),
+          config: config_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      let configData = await program.account.config.fetch(config_pkey);
+      assert.strictEqual(configData.admin.toString(), provider.wallet.publicKey.toString(), "The upgrade authority should be the admin");
+      assert.strictEqual(configData.moderators.length, 1, "Config should list one moderator");
+      assert.strictEqual(configData.moderators[0].toString(), charlie.publicKey.toString(), "Charlie should be a moderator");
+      assert.strictEqual(configData.bump, config_bump, `Config bump should be ${config_bump} but was ${configData.bump}`);
+    });
+
+    it("Should successfully transfer the admin to Bob", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+
+      const txSig = await program.methods.transferAdmin(bob.publicKey).accounts(
+        {
+          admin: provider.wallet.publicKey,
+          config: config_pkey,
+        }
+      ).rpc({ commitment: "confirmed" })
+
+      let configData = await program.account.config.fetch(config_pkey);
+      assert.strictEqual(configData.admin.toString(), bob.publicKey.toString(), "Bob should be the admin");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "adminTransferredEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.previousAdmin.toString(), provider.wallet.publicKey.toString(), "Event should name the previous admin");
+          assert.strictEqual(event.data.newAdmin.toString(), bob.publicKey.toString(), "Event should name the new admin");
+        }
+      }
+      assert.isTrue(eventEmitted, "AdminTransferredEvent should have been emitted");
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.transferAdmin(alice.publicKey).accounts(
+          {
+            admin: provider.wallet.publicKey,
+            config: config_pkey,
+          }
+        ).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "ConstraintHasOne", "Expected 'ConstraintHasOne' error when the previous admin transfers again")
+      }
+      assert.strictEqual(should_fail, "Failed", "The previous admin should no longer be able to transfer the config");
+    });
+
+    it("Should successfully report a tweet", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+      const [report_pkey, report_bump] = getReportAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      await program.methods.initialize(topic_moderated, "Please report me").accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_moderated, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      const txSig = await program.methods.reportTweet().accounts(
+        {
+          reporter: alice.publicKey,
+          report: report_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      let reportData = await program.account.report.fetch(report_pkey);
+      assert.strictEqual(reportData.reporter.toString(), alice.publicKey.toString(), "Reporter should be Alice");
+      assert.strictEqual(reportData.tweet.toString(), tweet_pkey.toString(), "Report should point at the tweet");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toNumber(), 1, "Tweet report count should be incremented");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "tweetReportedEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.reporter.toString(), alice.publicKey.toString(), "Event should name the reporter");
+          assert.strictEqual(event.data.reportCount.toNumber(), 1, "Event should carry the report count");
+        }
+      }
+      assert.isTrue(eventEmitted, "TweetReportedEvent should have been emitted");
+    });
+
+    it("Should fail to report the same tweet twice", async () => {
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_moderated);
+      const [report_pkey, report_bump] = getReportAddress(alice.publicKey, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.reportTweet().accounts(
+          {
+            reporter: alice.publicKey,
+            report: report_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        assert.isTrue(SolanaError.contains(error.logs, "already in use"), "Expected 'already in use' error when reporting twice")
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to report the same tweet twice");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toNumber(), 1, "Tweet report count should be unchanged");
+    });
+
+    it("Should fail when a non-moderator attempts to hide a tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_moderated);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.setTweetHidden(true).accounts(
+          {
+            moderator: alice.publicKey,
+            config: config_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotModerator", "Expected 'NotModerator' error when a regular user hides a tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Regular users should not be able to hide tweets");
+    });
+
+    it("Should block reactions, comments and retweets on a hidden tweet", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_moderated);
+      const [reaction_pkey, reaction_bump] = getReactionAddress(alice.publicKey, tweet_pkey, program.programId);
+      const [charlie_reaction_pkey, charlie_reaction_bump] = getReactionAddress(charlie.publicKey, tweet_pkey, program.programId);
+      const [retweet_pkey, retweet_bump] = getRetweetAddress(alice.publicKey, tweet_pkey, program.programId);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      // Charlie reacts while the tweet is still visible
+      await program.methods.likeTweet().accounts(
+        {
+          reactionAuthor: charlie.publicKey,
+          tweetReaction: charlie_reaction_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      const txSig = await program.methods.setTweetHidden(true).accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.isTrue(tweetData.hidden, "Tweet should be hidden");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "tweetHiddenEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.moderator.toString(), charlie.publicKey.toString(), "Event should name the moderator");
+          assert.isTrue(event.data.hidden, "Event should carry the hidden flag");
+        }
+      }
+      assert.isTrue(eventEmitted, "TweetHiddenEvent should have been emitted");
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.likeTweet().accounts(
+          {
+            reactionAuthor: alice.publicKey,
+            tweetReaction: reaction_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHidden", "Expected 'TweetHidden' error when reacting to a hidden tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to react to a hidden tweet");
+
+      should_fail = "This should fail";
+      try {
+        await program.methods.commentTweet("Can anyone see this?").accounts(
+          {
+            commentAuthor: alice.publicKey,
+            comment: comment_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHidden", "Expected 'TweetHidden' error when commenting on a hidden tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to comment on a hidden tweet");
+
+      const [quote_pkey, quote_bump] = await getNextTweetAddress(program, alice.publicKey);
+      should_fail = "This should fail";
+      try {
+        await program.methods.quoteTweet(topic_moderated, "Quoting what nobody can see").accounts(
+          {
+            tweetAuthority: alice.publicKey,
+            tweetCounter: getTweetCounterAddress(alice.publicKey, program.programId)[0],
+            tweet: quote_pkey,
+            topicAccount: getTopicAddress(topic_moderated, program.programId)[0],
+            quotedTweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHidden", "Expected 'TweetHidden' error when quoting a hidden tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to quote a hidden tweet");
+
+      should_fail = "This should fail";
+      try {
+        await program.methods.changeReaction({ dislike: {} }).accounts(
+          {
+            reactionAuthor: charlie.publicKey,
+            tweetReaction: charlie_reaction_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHidden", "Expected 'TweetHidden' error when changing a reaction on a hidden tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to change a reaction on a hidden tweet");
+
+      should_fail = "This should fail";
+      try {
+        await program.methods.retweet().accounts(
+          {
+            retweeter: alice.publicKey,
+            retweet: retweet_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([alice]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHidden", "Expected 'TweetHidden' error when retweeting a hidden tweet");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to retweet a hidden tweet");
+
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.likes.toNumber(), 1, "Charlie's like should be unchanged");
+      assert.strictEqual(tweetData.retweets.toNumber(), 0, "Hidden tweet should have no retweets");
+    });
+
+    it("Should block replies to a hidden comment", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_moderated);
+      const [comment_pkey, comment_bump] = await getNextCommentAddress(program, tweet_pkey);
+
+      await program.methods.setTweetHidden(false).accounts(
+        {
+          moderator: charlie.publicKey,
+          config: config_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([charlie]).rpc({ commitment: "confirmed" })
+
+      await program.methods.commentTweet("Visible again").accounts(
+        {
+          commentAuthor: alice.publicKey,
+          comment: comment_pkey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      await program.methods.setCommentHidden(true).accounts(
+        {
+          moderator: bob.publicKey,
+          config: config_pkey,
+          comment: comment_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      assert.isTrue(commentData.hidden, "Admin should be able to hide a comment");
+
+      const [reply_pkey, reply_bump] = await getNextCommentAddress(program, tweet_pkey);
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentReply("Replying anyway").accounts(
+          {
+            commentAuthor: charlie.publicKey,
+            comment: reply_pkey,
+            parentComment: comment_pkey,
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CommentHidden", "Expected 'CommentHidden' error when replying to a hidden comment");
+      }
+      assert.strictEqual(should_fail, "Failed", "Should not be able to reply to a hidden comment");
+    });
+
+    it("Should revoke moderation rights when the admin removes a moderator", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_moderated);
+
+      await program.methods.updateModerators([]).accounts(
+        {
+          admin: bob.publicKey,
+          config: config_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.setTweetHidden(true).accounts(
+          {
+            moderator: charlie.publicKey,
+            config: config_pkey,
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "NotModerator", "Expected 'NotModerator' error for a removed moderator");
+      }
+      assert.strictEqual(should_fail, "Failed", "Removed moderators should not be able to hide tweets");
+    });
+
+    it("Should fail to delete a tweet with open reports", async () => {
+      const [tweet_pkey, tweet_bump] = await getNextTweetAddress(program, bob.publicKey);
+
+      await program.methods.initialize(topic_reported, "Report me, then delete me").accounts(
+        {
+          tweetAuthority: bob.publicKey,
+          tweetCounter: getTweetCounterAddress(bob.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_reported, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      for (const reporter of [alice, charlie]) {
+        await program.methods.reportTweet().accounts(
+          {
+            reporter: reporter.publicKey,
+            report: getReportAddress(reporter.publicKey, tweet_pkey, program.programId)[0],
+            tweet: tweet_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([reporter]).rpc({ commitment: "confirmed" })
+      }
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: bob.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([bob]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasReports", "Expected 'TweetHasReports' error for a tweet with open reports");
+      }
+      assert.strictEqual(should_fail, "Failed", "Tweet deletion should fail while reports are open");
+    });
+
+    it("Should fail when someone other than the reporter or a moderator closes a report", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_reported);
+
+      // Charlie is no longer a moderator
+      let should_fail = "This should fail";
+      try {
+        await program.methods.closeReport().accounts(
+          {
+            closer: charlie.publicKey,
+            config: config_pkey,
+            reporter: alice.publicKey,
+            report: getReportAddress(alice.publicKey, tweet_pkey, program.programId)[0],
+            tweet: tweet_pkey,
+          }
+        ).signers([charlie]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "CannotCloseReport", "Expected 'CannotCloseReport' error when Charlie closes Alice's report");
+      }
+      assert.strictEqual(should_fail, "Failed", "Only the reporter or a moderator should be able to close a report");
+    });
+
+    it("Should let the reporter withdraw and the admin resolve reports before deletion", async () => {
+      const [config_pkey, config_bump] = getConfigAddress(program.programId);
+      const [tweet_pkey, tweet_bump] = await findTweetAddress(program, bob.publicKey, topic_reported);
+      const [alice_report_pkey, alice_report_bump] = getReportAddress(alice.publicKey, tweet_pkey, program.programId);
+      const [charlie_report_pkey, charlie_report_bump] = getReportAddress(charlie.publicKey, tweet_pkey, program.programId);
+
+      // Alice withdraws her own report without the config
+      await program.methods.closeReport().accounts(
+        {
+          closer: alice.publicKey,
+          config: null,
+          reporter: alice.publicKey,
+          report: alice_report_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([alice]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(alice_report_pkey, "confirmed"), "Alice's report should be closed");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toNumber(), 1, "Tweet report count should be decremented");
+
+      // Bob, the admin, resolves Charlie's report and the rent goes back to Charlie
+      const charlieBalance = await provider.connection.getBalance(charlie.publicKey, "confirmed");
+      const reportRent = (await provider.connection.getAccountInfo(charlie_report_pkey, "confirmed")).lamports;
+
+      const txSig = await program.methods.closeReport().accounts(
+        {
+          closer: bob.publicKey,
+          config: config_pkey,
+          reporter: charlie.publicKey,
+          report: charlie_report_pkey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(charlie_report_pkey, "confirmed"), "Charlie's report should be closed");
+      assert.strictEqual(await provider.connection.getBalance(charlie.publicKey, "confirmed"), charlieBalance + reportRent, "Report rent should go back to Charlie");
+      tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.reportCount.toNumber(), 0, "Tweet should have no open reports");
+
+      const tx = await provider.connection.getParsedTransaction(txSig, "confirmed");
+      const eventParser = new anchor.EventParser(program.programId, new anchor.BorshCoder(program.idl));
+      let eventEmitted = false;
+      for (let event of eventParser.parseLogs(tx.meta.logMessages)) {
+        if (event.name === "reportClosedEvent") {
+          eventEmitted = true;
+          assert.strictEqual(event.data.reporter.toString(), charlie.publicKey.toString(), "Event should name the reporter");
+          assert.strictEqual(event.data.closedBy.toString(), bob.publicKey.toString(), "Event should name who closed the report");
+          assert.strictEqual(event.data.reportCount.toNumber(), 0, "Event should carry the report count");
+        }
+      }
+      assert.isTrue(eventEmitted, "ReportClosedEvent should have been emitted");
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: bob.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).signers([bob]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(tweet_pkey, "confirmed"), "Tweet account should be closed");
+    });
+  });
+
+
+  describe("Legacy Comments", async () => {
+    // Accounts preloaded from tests/fixtures, as written by earlier versions of the program
+    const legacy_author = loadKeypair("tests/fixtures/legacy-author.json");
+    const topic_legacy = "Legacy";
+    const legacy_short = "Posted before comments were numbered";
+    const legacy_long = "Posted before comments were numbered, and long enough to outgrow its account. ".repeat(7).slice(0, 500);
+
+    it("Should remove a legacy comment without going below zero comments", async () => {
+      await airdrop(provider.connection, legacy_author.publicKey);
+      const [tweet_pkey, tweet_bump] = getTweetAddress(legacy_author.publicKey, 0, program.programId);
+      const [comment_pkey, comment_bump] = getLegacyCommentAddress(legacy_author.publicKey, legacy_short, tweet_pkey, program.programId);
+
+      await program.methods.initialize(topic_legacy, "Comments from the old days").accounts(
+        {
+          tweetAuthority: legacy_author.publicKey,
+          tweetCounter: getTweetCounterAddress(legacy_author.publicKey, program.programId)[0],
+          tweet: tweet_pkey,
+          topicAccount: getTopicAddress(topic_legacy, program.programId)[0],
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      let commentData = await program.account.comment.fetch(comment_pkey);
+      assert.isNull(commentData.commentId, "Legacy comment should have no comment id");
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: legacy_author.publicKey,
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          parentComment: null,
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(comment_pkey, "confirmed"), "Legacy comment account should be closed");
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.strictEqual(tweetData.commentCount.toNumber(), 0, "Tweet comment count should stay at 0");
+    });
+
+    it("Should migrate a long legacy comment before removing it", async () => {
+      const [tweet_pkey, tweet_bump] = getTweetAddress(legacy_author.publicKey, 0, program.programId);
+      const [comment_pkey, comment_bump] = getLegacyCommentAddress(legacy_author.publicKey, legacy_long, tweet_pkey, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.commentRemove().accounts(
+          {
+            commentAuthor: legacy_author.publicKey,
+            comment: comment_pkey,
+            parentTweet: tweet_pkey,
+            parentComment: null,
+          }
+        ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AccountDidNotDeserialize", "Expected 'AccountDidNotDeserialize' error before migration");
+      }
+      assert.strictEqual(should_fail, "Failed", "Long legacy comment should not deserialize before migration");
+
+      await program.methods.migrateComment().accounts(
+        {
+          payer: legacy_author.publicKey,
+          comment: comment_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      const commentInfo = await provider.connection.getAccountInfo(comment_pkey, "confirmed");
+      assert.strictEqual(commentInfo.data.length, program.account.comment.size, "Comment should be grown to the current layout");
+      await checkComment(program, comment_pkey, legacy_author.publicKey, tweet_pkey, legacy_long, comment_bump);
+
+      should_fail = "This should fail";
+      try {
+        await program.methods.migrateComment().accounts(
+          {
+            payer: legacy_author.publicKey,
+            comment: comment_pkey,
+            systemProgram: anchor.web3.SystemProgram.programId
+          }
+        ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AccountUpToDate", "Expected 'AccountUpToDate' error for a migrated comment");
+      }
+      assert.strictEqual(should_fail, "Failed", "Comment should only be migrated once");
+
+      await program.methods.commentRemove().accounts(
+        {
+          commentAuthor: legacy_author.publicKey,
+          comment: comment_pkey,
+          parentTweet: tweet_pkey,
+          parentComment: null,
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(comment_pkey, "confirmed"), "Legacy comment account should be closed");
+    });
+  });
+
+
+  describe("Legacy Tweets", async () => {
+    // Accounts preloaded from tests/fixtures, as written by earlier versions of the program
+    const legacy_author = loadKeypair("tests/fixtures/legacy-author.json");
+    const topic_legacy_short = "Legacy days";
+    const content_legacy_short = "Tweeted before tweets were numbered";
+    const topic_legacy_long = "L".repeat(32);
+
+    it("Should edit and delete a tweet created under the legacy seeds", async () => {
+      await airdrop(provider.connection, legacy_author.publicKey);
+      const [tweet_pkey, tweet_bump] = getLegacyTweetAddress(legacy_author.publicKey, topic_legacy_short, program.programId);
+      const [version_pkey, version_bump] = getTweetVersionAddress(tweet_pkey, 0, program.programId);
+
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.isNull(tweetData.tweetId, "Legacy tweet should have no tweet id");
+
+      await program.methods.editTweet("Edited long after it was posted").accounts(
+        {
+          tweetAuthor: legacy_author.publicKey,
+          tweet: tweet_pkey,
+          tweetVersion: version_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      await checkTweet(
+        program, tweet_pkey, legacy_author.publicKey, topic_legacy_short, "Edited long after it was posted", 0, 0, tweet_bump
+      )
+      let versionData = await program.account.tweetVersion.fetch(version_pkey);
+      assert.strictEqual(versionData.content, content_legacy_short, "Version should keep the legacy content");
+
+      await program.methods.deleteTweet().accounts(
+        {
+          tweetAuthor: legacy_author.publicKey,
+          tweet: tweet_pkey,
+        }
+      ).remainingAccounts([
+        { pubkey: version_pkey, isWritable: true, isSigner: false },
+      ]).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      assert.isNull(await provider.connection.getAccountInfo(tweet_pkey, "confirmed"), "Legacy tweet account should be closed");
+      assert.isNull(await provider.connection.getAccountInfo(version_pkey, "confirmed"), "Tweet version account should be closed");
+    });
+
+    it("Should migrate a long legacy tweet and keep its reaction counts", async () => {
+      const [tweet_pkey, tweet_bump] = getLegacyTweetAddress(legacy_author.publicKey, topic_legacy_long, program.programId);
+
+      let should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: legacy_author.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "AccountDidNotDeserialize", "Expected 'AccountDidNotDeserialize' error before migration");
+      }
+      assert.strictEqual(should_fail, "Failed", "Long legacy tweet should not deserialize before migration");
+
+      await program.methods.migrateTweet().accounts(
+        {
+          payer: legacy_author.publicKey,
+          tweet: tweet_pkey,
+          systemProgram: anchor.web3.SystemProgram.programId
+        }
+      ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+
+      const tweetInfo = await provider.connection.getAccountInfo(tweet_pkey, "confirmed");
+      assert.strictEqual(tweetInfo.data.length, program.account.tweet.size, "Tweet should be grown to the current layout");
+      // The fixture was written with 2 likes and 1 dislike
+      await checkTweet(program, tweet_pkey, legacy_author.publicKey, topic_legacy_long, undefined, 2, 1, tweet_bump);
+      let tweetData = await program.account.tweet.fetch(tweet_pkey);
+      assert.deepEqual(tweetData.reactions, [0, 0, 0, 0], "Legacy tweet should have no extra reactions");
+
+      should_fail = "This should fail";
+      try {
+        await program.methods.deleteTweet().accounts(
+          {
+            tweetAuthor: legacy_author.publicKey,
+            tweet: tweet_pkey,
+          }
+        ).signers([legacy_author]).rpc({ commitment: "confirmed" })
+      } catch (error) {
+        should_fail = "Failed"
+        const err = anchor.AnchorError.parse(error.logs);
+        assert.strictEqual(err.error.errorCode.code, "TweetHasReactions", "Expected 'TweetHasReactions' error for a legacy tweet with likes");
+      }
+      assert.strictEqual(should_fail, "Failed", "Legacy tweet with likes should not be deletable");
+    });
+  });
+
+});
+
+
+async function airdrop(connection: any, address: any, amount = 1000000000) {
+  await connection.confirmTransaction(await connection.requestAirdrop(address, amount), "confirmed");
+}
+
+function getCommentAddress(parent_tweet: PublicKey, comment_id: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
+      parent_tweet.toBuffer(),
+      new anchor.BN(comment_id).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
+async function getNextCommentAddress(program: anchor.Program<Twitter>, parent_tweet: PublicKey) {
+  const tweetData = await program.account.tweet.fetch(parent_tweet);
+  return getCommentAddress(parent_tweet, tweetData.nextCommentId.toNumber(), program.programId);
+}
+
+// Finds the first comment on a tweet with the given author and content, or the
+// next unused comment address if there is none
+async function findCommentAddress(program: anchor.Program<Twitter>, parent_tweet: PublicKey, author: PublicKey, content: string) {
+  const tweetData = await program.account.tweet.fetch(parent_tweet);
+  for (let comment_id = 0; comment_id < tweetData.nextCommentId.toNumber(); comment_id++) {
+    const [comment_pkey, comment_bump] = getCommentAddress(parent_tweet, comment_id, program.programId);
+    const commentData = await program.account.comment.fetchNullable(comment_pkey);
+    if (commentData && commentData.commentAuthor.equals(author) && commentData.content === content) {
+      return [comment_pkey, comment_bump] as [PublicKey, number];
+    }
+  }
+  return getCommentAddress(parent_tweet, tweetData.nextCommentId.toNumber(), program.programId);
+}
+
+function getTweetCounterAddress(author: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_COUNTER_SEED),
+      author.toBuffer(),
+    ], programID);
+}
+
+function getTweetAddress(author: PublicKey, tweet_id: number, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_SEED),
+      author.toBuffer(),
+      new anchor.BN(tweet_id).toArrayLike(Buffer, "le", 8),
+    ], programID);
+}
+
+async function getNextTweetAddress(program: anchor.Program<Twitter>, author: PublicKey) {
+  const [counter_pkey, counter_bump] = getTweetCounterAddress(author, program.programId);
+  const counterData = await program.account.tweetCounter.fetchNullable(counter_pkey);
+  return getTweetAddress(author, counterData ? counterData.nextTweetId.toNumber() : 0, program.programId);
+}
+
+async function findTweetAddress(program: anchor.Program<Twitter>, author: PublicKey, topic: string) {
+  const [counter_pkey, counter_bump] = getTweetCounterAddress(author, program.programId);
+  const counterData = await program.account.tweetCounter.fetchNullable(counter_pkey);
+  const next_tweet_id = counterData ? counterData.nextTweetId.toNumber() : 0;
+  for (let tweet_id = 0; tweet_id < next_tweet_id; tweet_id++) {
+    const [tweet_pkey, tweet_bump] = getTweetAddress(author, tweet_id, program.programId);
+    const tweetData = await program.account.tweet.fetchNullable(tweet_pkey);
+    if (tweetData && tweetData.topic === topic) {
+      return [tweet_pkey, tweet_bump] as [PublicKey, number];
+    }
+  }
+  return getTweetAddress(author, next_tweet_id, program.programId);
+}
+
+function getReactionAddress(author: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_REACTION),
+      author.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getTweetVersionAddress(tweet: PublicKey, version: number, programID: PublicKey) {
+  const version_seed = Buffer.alloc(4);
+  version_seed.writeUInt32LE(version);
+
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TWEET_VERSION_SEED),
+      tweet.toBuffer(),
+      version_seed,
+    ], programID);
+}
+
+function getProfileAddress(authority: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(PROFILE_SEED),
+      authority.toBuffer(),
+    ], programID);
+}
+
+function getHandleAddress(handle: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(HANDLE_SEED),
+      anchor.utils.bytes.utf8.encode(handle.toLowerCase()),
+    ], programID);
+}
+
+function getFollowAddress(follower: PublicKey, followee: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(FOLLOW_SEED),
+      follower.toBuffer(),
+      followee.toBuffer(),
+    ], programID);
+}
+
+function getRetweetAddress(retweeter: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(RETWEET_SEED),
+      retweeter.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function getTopicAddress(topic: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(TOPIC_SEED),
+      crypto.createHash('sha256').update(topic.trim().toLowerCase(), 'utf-8').digest(),
+    ], programID);
+}
+
+function getConfigAddress(programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(CONFIG_SEED),
+    ], programID);
+}
+
+function getReportAddress(reporter: PublicKey, tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(REPORT_SEED),
+      reporter.toBuffer(),
+      tweet.toBuffer(),
+    ], programID);
+}
+
+function loadKeypair(path: string) {
+  return anchor.web3.Keypair.fromSecretKey(Uint8Array.from(JSON.parse(fs.readFileSync(path, "utf-8"))));
+}
+
+function getLegacyCommentAddress(author: PublicKey, content: string, parent_tweet: PublicKey, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(COMMENT_SEED),
+      author.toBuffer(),
+      crypto.createHash('sha256').update(content, 'utf-8').digest(),
+      parent_tweet.toBuffer(),
+    ], programID);
+}
+
+function getLegacyTweetAddress(author: PublicKey, topic: string, programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [
+      anchor.utils.bytes.utf8.encode(topic),
+      anchor.utils.bytes.utf8.encode(TWEET_SEED),
+      author.toBuffer(),
+    ], programID);
+}
+
+function getProgramDataAddress(programID: PublicKey) {
+  return PublicKey.findProgramAddressSync(
+    [programID.toBuffer()],
+    new PublicKey("BPFLoaderUpgradeab1e11111111111111111111111"))[0];
 }
 
 class SolanaError {